@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = lolid::Uuid::parse_ascii_bytes(data);
+
+    if let Ok(input) = core::str::from_utf8(data) {
+        if let Ok(uuid) = lolid::Uuid::try_parse(input) {
+            assert_eq!(lolid::Uuid::parse_str(uuid.to_str().as_str()), Ok(uuid));
+        }
+        let _ = lolid::Uuid::parse_prefix(input);
+        let _ = input.parse::<lolid::Simple>();
+        let _ = input.parse::<lolid::Hyphenated>();
+    }
+});
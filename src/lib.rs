@@ -6,12 +6,16 @@
 //!- `prng`  - Enables v4 using pseudo random, allowing unique, but predictable UUIDs;
 //!- `orng`  - Enables v4 using OS random, allowing unique UUIDs;
 //!- `sha1`  - Enables v5;
+//!- `sha1-compact` - Enables v5 using built-in `SHA-1` implementation, when `sha1` is not enabled;
 //!- `serde` - Enables `serde` support;
+//!- `subtle` - Enables constant time comparison via `subtle`;
+//!- `libm`  - Enables floating point math in `no_std` (e.g. `collision_probability`);
+//!- `rand`  - Enables generation of v4 via `rand`;
 //!- `std`   - Enables usages of `std` facilities like getting current time.
 
 #![no_std]
 #![warn(missing_docs)]
-#![cfg_attr(feature = "cargo-clippy", allow(clippy::style))]
+#![allow(clippy::style)]
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -19,22 +23,44 @@ extern crate std;
 use core::{fmt, time, mem};
 
 #[cfg(feature = "serde")]
-mod serde;
+pub mod serde;
+#[cfg(feature = "sha1-compact")]
+#[cfg_attr(feature = "sha1", allow(dead_code))]
+mod sha1;
+
+#[cfg(feature = "sha1")]
+use lhash::Sha1;
+#[cfg(all(feature = "sha1-compact", not(feature = "sha1")))]
+use crate::sha1::Sha1;
 
 type StrBuf = str_buf::StrBuf<36>;
 const SEP: u8 = b'-';
 
 #[inline(always)]
 const fn byte_to_hex(byt: u8, idx: usize) -> u8 {
+    byte_to_hex_case::<false>(byt, idx)
+}
+
+#[inline(always)]
+const fn byte_to_hex_case<const UPPER: bool>(byt: u8, idx: usize) -> u8 {
     const BASE: usize = 4;
     const BASE_DIGIT: usize = (1 << BASE) - 1;
     const HEX_DIGITS: [u8; 16] = [b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'a', b'b', b'c', b'd', b'e', b'f'];
+    const HEX_DIGITS_UPPER: [u8; 16] = [b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'A', b'B', b'C', b'D', b'E', b'F'];
 
-    HEX_DIGITS[((byt as usize) >> (BASE * idx)) & BASE_DIGIT]
+    let digits = match UPPER {
+        true => &HEX_DIGITS_UPPER,
+        false => &HEX_DIGITS,
+    };
+    digits[((byt as usize) >> (BASE * idx)) & BASE_DIGIT]
 }
 
 #[inline]
 const fn hex_to_byte(hex: &[u8], cursor: usize) -> Result<u8, ParseError> {
+    if cursor + 1 >= hex.len() {
+        return Err(ParseError::InvalidLength(hex.len()));
+    }
+
     let left = match hex[cursor] {
         chr @ b'0'..=b'9' => chr - b'0',
         chr @ b'a'..=b'f' => chr - b'a' + 10,
@@ -52,6 +78,27 @@ const fn hex_to_byte(hex: &[u8], cursor: usize) -> Result<u8, ParseError> {
     Ok(left * 16 + right)
 }
 
+const URN_PREFIX: &[u8] = b"urn:uuid:";
+//Only colons are encoded, as it is typical for URN within URL query
+const URN_PREFIX_ENCODED: &[u8] = b"urn%3auuid%3a";
+
+#[inline]
+const fn starts_with_ignore_case(input: &[u8], prefix: &[u8]) -> bool {
+    if input.len() < prefix.len() {
+        return false;
+    }
+
+    let mut idx = 0;
+    while idx < prefix.len() {
+        if input[idx].to_ascii_lowercase() != prefix[idx] {
+            return false;
+        }
+        idx += 1;
+    }
+
+    true
+}
+
 macro_rules! hex_to_byte_try {
     ($bytes:expr, $cursor:expr) => {
         match hex_to_byte($bytes, $cursor) {
@@ -61,6 +108,36 @@ macro_rules! hex_to_byte_try {
     }
 }
 
+#[cfg(feature = "md5")]
+#[macro_export]
+///Creates UUID `v3` at compile time.
+///
+///Accepts namespace and name as `&str`.
+///As `Uuid::v3` is `const fn`, this macro only guarantees that evaluation happens at compile time.
+///
+///Only available when `md5` feature is enabled.
+macro_rules! const_v3 {
+    ($namespace:expr, $name:expr) => {{
+        const UUID: $crate::Uuid = $crate::Uuid::v3($namespace, $name.as_bytes());
+        UUID
+    }}
+}
+
+#[cfg(any(feature = "sha1", feature = "sha1-compact"))]
+#[macro_export]
+///Creates UUID `v5` at compile time.
+///
+///Accepts namespace and name as `&str`.
+///As `Uuid::v5` is `const fn`, this macro only guarantees that evaluation happens at compile time.
+///
+///Only available when `sha1` or `sha1-compact` feature is enabled.
+macro_rules! const_v5 {
+    ($namespace:expr, $name:expr) => {{
+        const UUID: $crate::Uuid = $crate::Uuid::v5($namespace, $name.as_bytes());
+        UUID
+    }}
+}
+
 ///When this namespace is specified, the name string is a fully-qualified domain name
 pub const NAMESPACE_DNS: Uuid = Uuid::from_bytes([
      0x6b, 0xa7, 0xb8, 0x10, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8
@@ -98,6 +175,42 @@ pub enum Version {
     Sha1,
 }
 
+/// The variant of the UUID, denoting the layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variant {
+    /// Reserved for NCS backward compatibility.
+    Ncs,
+    /// Layout specified by RFC4122.
+    Rfc4122,
+    /// Reserved for Microsoft backward compatibility.
+    Microsoft,
+    /// Reserved for future definition.
+    Future,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+///Textual form of UUID.
+pub enum Format {
+    ///Hyphenated form, i.e. `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`.
+    Hyphenated,
+    ///Simple form, i.e. 32 hex characters without separators.
+    Simple,
+    ///Hyphenated form prefixed with `urn:uuid:`.
+    Urn,
+}
+
+impl Format {
+    #[inline]
+    ///Returns length of UUID in this form.
+    pub const fn len(self) -> usize {
+        match self {
+            Format::Hyphenated => StrBuf::capacity(),
+            Format::Simple => UUID_SIZE * 2,
+            Format::Urn => URN_PREFIX.len() + StrBuf::capacity(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Copy)]
 ///Timestamp for use with `v1` algorithm.
 pub struct Timestamp {
@@ -106,6 +219,7 @@ pub struct Timestamp {
 }
 
 const V1_NS_TICKS: u64 = 0x01B2_1DD2_1381_4000;
+const V1_MAX_TICKS: u64 = 0x0FFF_FFFF_FFFF_FFFF;
 
 impl Timestamp {
     #[inline(always)]
@@ -158,6 +272,169 @@ impl Timestamp {
     pub const fn into_parts(self) -> (u64, u16) {
         (self.ticks, self.counter)
     }
+
+    #[inline(always)]
+    ///Returns number of 100-nanoseconds intervals elapsed since 15 Oct 1582 00:00:00.00.
+    pub const fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    #[inline(always)]
+    ///Returns counter.
+    pub const fn counter(&self) -> u16 {
+        self.counter
+    }
+}
+
+impl From<Timestamp> for (u64, u16) {
+    #[inline(always)]
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.into_parts()
+    }
+}
+
+impl From<(u64, u16)> for Timestamp {
+    #[inline(always)]
+    fn from((ticks, counter): (u64, u16)) -> Self {
+        Self::from_parts(ticks, counter)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+///State of `v1` generator, guaranteeing that produced timestamps are unique even if clock is
+///coarse or goes backwards.
+///
+///It is not synchronized, hence must be used by single generator.
+pub struct V1State {
+    last: u64,
+    counter: u16,
+}
+
+impl V1State {
+    //Clock sequence is 14-bit, so bigger counter would be truncated by `v1`
+    const MAX_COUNTER: u16 = 0x3FFF;
+
+    #[inline]
+    ///Creates new state.
+    pub const fn new() -> Self {
+        Self {
+            last: 0,
+            counter: 0,
+        }
+    }
+
+    ///Returns next unique timestamp, given current time as `now_ticks`.
+    ///
+    ///If `now_ticks` is not after the last one, counter is incremented.
+    ///Once counter reaches 14 bits limit, ticks are incremented instead and counter is reset.
+    ///Otherwise `now_ticks` is used with counter reset.
+    ///
+    ///Fails with `TimestampOverflow` if ticks would exceed 60 bits, which `v1` can store, in
+    ///which case state is not modified.
+    pub fn next_timestamp(&mut self, now_ticks: u64) -> Result<Timestamp, FieldsError> {
+        if now_ticks > V1_MAX_TICKS {
+            return Err(FieldsError::TimestampOverflow(now_ticks));
+        }
+
+        if now_ticks > self.last {
+            self.last = now_ticks;
+            self.counter = 0;
+        } else if self.counter == Self::MAX_COUNTER {
+            if self.last == V1_MAX_TICKS {
+                return Err(FieldsError::TimestampOverflow(self.last + 1));
+            }
+            self.last += 1;
+            self.counter = 0;
+        } else {
+            self.counter += 1;
+        }
+
+        Ok(Timestamp::from_parts(self.last, self.counter))
+    }
+}
+
+///Source of random bytes.
+pub trait Rng {
+    ///Fills `out` with random bytes.
+    fn fill_bytes(&mut self, out: &mut [u8]);
+}
+
+#[cfg(feature = "osrng")]
+#[derive(Clone, Copy, Debug, Default)]
+///OS RNG from [getrandom](https://crates.io/crates/getrandom)
+///
+///Only available when `osrng` feature is enabled.
+pub struct OsRng;
+
+#[cfg(feature = "osrng")]
+impl Rng for OsRng {
+    #[inline]
+    fn fill_bytes(&mut self, out: &mut [u8]) {
+        if let Err(error) = getrandom::getrandom(out) {
+            panic!("OS RNG is not available for use: {}", error)
+        }
+    }
+}
+
+#[cfg(feature = "prng")]
+impl Rng for wy::Random {
+    #[inline]
+    fn fill_bytes(&mut self, out: &mut [u8]) {
+        for chunk in out.chunks_mut(mem::size_of::<u64>()) {
+            let random = self.gen().to_ne_bytes();
+            chunk.copy_from_slice(&random[..chunk.len()]);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+///Inputs for `Uuid::generate`.
+///
+///Only inputs required by the selected version are used, the rest are ignored.
+pub struct GenerateOpts<'a> {
+    ///Timestamp, required by `v1`.
+    pub timestamp: Option<Timestamp>,
+    ///Node, required by `v1`.
+    pub node: Option<[u8; 6]>,
+    ///Namespace, required by `v3` and `v5`.
+    pub namespace: Option<Uuid>,
+    ///Name, required by `v3` and `v5`.
+    pub name: Option<&'a [u8]>,
+}
+
+impl<'a> GenerateOpts<'a> {
+    #[inline(always)]
+    ///Creates empty options.
+    pub const fn new() -> Self {
+        Self {
+            timestamp: None,
+            node: None,
+            namespace: None,
+            name: None,
+        }
+    }
+
+    #[inline(always)]
+    ///Sets timestamp.
+    pub const fn timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    #[inline(always)]
+    ///Sets node.
+    pub const fn node(mut self, node: [u8; 6]) -> Self {
+        self.node = Some(node);
+        self
+    }
+
+    #[inline(always)]
+    ///Sets namespace and name.
+    pub const fn name(mut self, namespace: Uuid, name: &'a [u8]) -> Self {
+        self.namespace = Some(namespace);
+        self.name = Some(name);
+        self
+    }
 }
 
 const UUID_SIZE: usize = 16;
@@ -176,12 +453,27 @@ impl Uuid {
         Self::from_bytes([0; UUID_SIZE])
     }
 
+    #[inline]
+    ///Checks whether UUID is `nil`
+    pub const fn is_nil(&self) -> bool {
+        u128::from_ne_bytes(self.data) == 0
+    }
+
     #[inline]
     ///Creates new Uuid from raw bytes.
     pub const fn from_bytes(data: [u8; UUID_SIZE]) -> Self {
         Self { data }
     }
 
+    #[inline(always)]
+    ///Reinterprets reference to raw bytes as reference to Uuid, without copying.
+    pub const fn from_bytes_ref(data: &[u8; UUID_SIZE]) -> &Self {
+        //Uuid is `repr(transparent)` over byte array, so layout is identical
+        unsafe {
+            &*(data as *const [u8; UUID_SIZE] as *const Self)
+        }
+    }
+
     #[inline]
     ///Creates new Uuid from byte slice, if its size is 16, otherwise `None`
     pub const fn from_slice(data: &[u8]) -> Option<Uuid> {
@@ -227,6 +519,77 @@ impl Uuid {
         ])
     }
 
+    ///Creates `UUID` from bytes of unknown layout, guessing whether they are `GUID` (little endian
+    ///integer fields) or `UUID` (big endian) by looking at version of each interpretation.
+    ///
+    ///Returns interpretation whose version matches `expect_version`, or `None` if neither or both match.
+    ///
+    ///Note that it is only heuristic and not foolproof: bytes `6` and `7` must differ in version
+    ///nibble in order to tell layouts apart.
+    pub const fn from_bytes_detect_guid(bytes: [u8; 16], expect_version: Version) -> Option<Self> {
+        let straight = Self::from_bytes(bytes);
+        let swapped = Self::from_guid(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+                                      u16::from_le_bytes([bytes[4], bytes[5]]),
+                                      u16::from_le_bytes([bytes[6], bytes[7]]),
+                                      [bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15]]);
+
+        match (straight.is_version(expect_version), swapped.is_version(expect_version)) {
+            (true, false) => Some(straight),
+            (false, true) => Some(swapped),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    ///Creates UUID from fields, written in big endian as per RFC4122.
+    ///
+    ///- `time_hi_and_version` is written as it is, hence it must include version;
+    ///- `clock_seq` is 14-bit clock sequence, excess bits are discarded to set RFC4122 variant.
+    pub const fn from_fields(time_low: u32, time_mid: u16, time_hi_and_version: u16, clock_seq: u16, node: [u8; 6]) -> Self {
+        Builder::new().set_time_low(time_low)
+                      .set_time_mid(time_mid)
+                      .set_time_high(time_hi_and_version)
+                      .set_version_number((time_hi_and_version >> 12) as u8)
+                      .set_clock_seq(clock_seq)
+                      .set_variant()
+                      .set_node(node)
+                      .build()
+    }
+
+    #[inline]
+    ///Creates UUID from fields, same as `from_fields`, but verifies that fields are consistent.
+    ///
+    ///Fails if version within `time_hi_and_version` is not `version` or `clock_seq` doesn't fit
+    ///14 bits.
+    pub const fn checked_from_fields(version: Version, time_low: u32, time_mid: u16, time_hi_and_version: u16, clock_seq: u16, node: [u8; 6]) -> Result<Self, FieldsError> {
+        let actual_version = (time_hi_and_version >> 12) as u8;
+        if actual_version != version as u8 {
+            return Err(FieldsError::VersionMismatch(actual_version));
+        } else if clock_seq > 0x3FFF {
+            return Err(FieldsError::ClockSeqOverflow(clock_seq));
+        }
+
+        Ok(Self::from_fields(time_low, time_mid, time_hi_and_version, clock_seq, node))
+    }
+
+    #[inline]
+    ///Creates UUID from bytes, verifying that it is RFC4122 UUID.
+    ///
+    ///Fails with `InvalidVariant` if variant is not `Rfc4122` or with `InvalidVersion` if
+    ///version is not within `1..=8`.
+    pub const fn from_bytes_rfc4122(data: [u8; UUID_SIZE]) -> Result<Self, ParseError> {
+        let uuid = Self::from_bytes(data);
+        match uuid.variant() {
+            Variant::Rfc4122 => (),
+            variant => return Err(ParseError::InvalidVariant(variant)),
+        }
+
+        match uuid.version_number() {
+            1..=8 => Ok(uuid),
+            version => Err(ParseError::InvalidVersion(version)),
+        }
+    }
+
     #[inline]
     ///Access underlying bytes as slice.
     pub const fn as_bytes(&self) -> &[u8] {
@@ -239,6 +602,34 @@ impl Uuid {
         self.data
     }
 
+    #[inline(always)]
+    ///Creates new Uuid from 128-bit integer, interpreting it as big endian.
+    pub const fn from_u128(data: u128) -> Self {
+        Self::from_bytes(data.to_be_bytes())
+    }
+
+    #[inline(always)]
+    ///Returns UUID as 128-bit integer, interpreting bytes as big endian.
+    pub const fn as_u128(&self) -> u128 {
+        u128::from_be_bytes(self.data)
+    }
+
+    #[inline]
+    ///Creates UUID from pair of signed 64-bit integers, where `high` is first 8 bytes and `low`
+    ///is last 8 bytes in big endian.
+    ///
+    ///Integers are re-interpreted bit-for-bit, e.g. to be stored as two `BIGINT` columns.
+    pub const fn from_i64_pair(high: i64, low: i64) -> Self {
+        Self::from_u128(((high as u64 as u128) << 64) | (low as u64 as u128))
+    }
+
+    #[inline]
+    ///Returns UUID as pair of signed 64-bit integers, reverse of `from_i64_pair`.
+    pub const fn as_i64_pair(&self) -> (i64, i64) {
+        let data = self.as_u128();
+        ((data >> 64) as u64 as i64, data as u64 as i64)
+    }
+
     #[inline]
     ///Returns `time_low` part of uuid.
     ///
@@ -271,6 +662,30 @@ impl Uuid {
         u16::from_ne_bytes([self.data[8], self.data[9]])
     }
 
+    #[inline]
+    ///Checks whether `node` has multicast bit set.
+    ///
+    ///For `v1` it indicates that `node` is random rather than hardware MAC address.
+    pub const fn node_is_multicast(&self) -> bool {
+        self.data[10] & 0x01 == 0x01
+    }
+
+    #[inline]
+    ///Checks whether `node` has locally administered bit set.
+    ///
+    ///For `v1` it indicates that `node` is not globally unique hardware MAC address.
+    pub const fn node_is_local(&self) -> bool {
+        self.data[10] & 0x02 == 0x02
+    }
+
+    #[inline]
+    ///Returns 14-bit clock sequence, excluding variant bits.
+    ///
+    ///Only meaningful for time based UUID (i.e. `v1`), where it corresponds to `Timestamp` counter.
+    pub const fn clock_seq(&self) -> u16 {
+        (((self.data[8] & 0x3f) as u16) << 8) | self.data[9] as u16
+    }
+
     #[inline]
     ///Returns `node` part of uuid.
     ///
@@ -279,6 +694,86 @@ impl Uuid {
         [self.data[10], self.data[11], self.data[12], self.data[13], self.data[14], self.data[15]]
     }
 
+    #[cfg(feature = "subtle")]
+    #[inline]
+    ///Compares UUIDs in constant time, without branching on content of bytes.
+    ///
+    ///Use it instead of `==` when UUID is derived from secret data.
+    ///
+    ///Only available when `subtle` feature is enabled.
+    pub fn ct_eq(&self, other: &Uuid) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(&self.data[..], &other.data[..])
+    }
+
+    #[inline]
+    ///Returns raw version number.
+    pub const fn version_number(&self) -> u8 {
+        self.data[6] >> 4
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    ///Returns time of creation for UUID `v7`, stored as milliseconds since unix epoch in the
+    ///first 48 bits.
+    ///
+    ///Returns `None` if version is not 7.
+    ///
+    ///Only available when `std` feature is enabled.
+    pub fn v7_system_time(&self) -> Option<std::time::SystemTime> {
+        if self.version_number() != 7 {
+            return None;
+        }
+
+        let data = &self.data;
+        let millis = u64::from_be_bytes([0, 0, data[0], data[1], data[2], data[3], data[4], data[5]]);
+        Some(std::time::UNIX_EPOCH + time::Duration::from_millis(millis))
+    }
+
+    ///Checks whether embedded timestamp is strictly before `cutoff`, comparing ticks only.
+    ///
+    ///Supports `v1` and `v7`, where `v7` milliseconds are converted to ticks.
+    ///Returns `None` for versions without timestamp.
+    pub const fn timestamp_before(&self, cutoff: Timestamp) -> Option<bool> {
+        let data = &self.data;
+        let ticks = match self.version_number() {
+            1 => u64::from_be_bytes([data[6] & 0x0f, data[7], data[4], data[5], data[0], data[1], data[2], data[3]]),
+            7 => {
+                let millis = u64::from_be_bytes([0, 0, data[0], data[1], data[2], data[3], data[4], data[5]]);
+                V1_NS_TICKS.saturating_add(millis.saturating_mul(10_000))
+            },
+            _ => return None,
+        };
+
+        Some(ticks < cutoff.ticks)
+    }
+
+    #[inline]
+    ///Reverses order of all 16 bytes.
+    ///
+    ///Unlike `GUID` conversion, which swaps bytes within first three fields only, it reverses
+    ///whole 128-bit value, e.g. to transport it as little endian integer.
+    pub const fn reverse_bytes(&self) -> Self {
+        Self::from_u128(u128::from_le_bytes(self.data))
+    }
+
+    #[inline]
+    ///Compares raw bytes lexicographically, same as `Ord`.
+    ///
+    ///Usable in const context and as plain function pointer (e.g. `sort_by(Uuid::cmp_bytes)`).
+    pub const fn cmp_bytes(left: &Uuid, right: &Uuid) -> core::cmp::Ordering {
+        let mut idx = 0;
+        while idx < UUID_SIZE {
+            if left.data[idx] < right.data[idx] {
+                return core::cmp::Ordering::Less;
+            } else if left.data[idx] > right.data[idx] {
+                return core::cmp::Ordering::Greater;
+            }
+            idx += 1;
+        }
+
+        core::cmp::Ordering::Equal
+    }
+
     #[inline]
     ///Checks if `UUID` version is equal to the provided `version`
     pub const fn is_version(&self, version: Version) -> bool {
@@ -291,30 +786,75 @@ impl Uuid {
         (self.data[8] & 0xc0) == 0x80
     }
 
+    #[inline]
+    ///Returns variant, determined by the most significant bits of byte `8`.
+    pub const fn variant(&self) -> Variant {
+        match self.data[8] >> 5 {
+            0b000..=0b011 => Variant::Ncs,
+            0b100..=0b101 => Variant::Rfc4122,
+            0b110 => Variant::Microsoft,
+            _ => Variant::Future,
+        }
+    }
+
     ///Generates UUID from time and mac address
+    ///
+    ///Only lower 60 bits of `timestamp` ticks are stored, the rest is discarded, which happens
+    ///for dates after year 5236.
+    ///
+    ///# Panics
+    ///
+    ///In debug builds it panics if `timestamp` ticks exceed 60 bits, use `v1_checked` to handle it.
     pub const fn v1(timestamp: Timestamp, mac: [u8; 6]) -> Self {
-        let time_low = (timestamp.ticks & 0xFFFF_FFFF) as u32;
-        let time_mid = ((timestamp.ticks >> 32) & 0xFFFF) as u16;
-        let time_high_and_version = (((timestamp.ticks >> 48) & 0x0FFF) as u16) | (1 << 12);
+        debug_assert!(timestamp.ticks <= V1_MAX_TICKS, "Timestamp ticks exceed 60 bits");
+        Builder::new().set_time_low((timestamp.ticks & 0xFFFF_FFFF) as u32)
+                      .set_time_mid(((timestamp.ticks >> 32) & 0xFFFF) as u16)
+                      .set_time_high(((timestamp.ticks >> 48) & 0x0FFF) as u16)
+                      .set_clock_seq(timestamp.counter)
+                      .set_node(mac)
+                      .set_variant()
+                      .set_version(Version::Mac)
+                      .build()
+    }
 
-        Self::from_bytes([
-            (time_low >> 24) as u8,
-            (time_low >> 16) as u8,
-            (time_low >> 8) as u8,
-            time_low as u8,
-            (time_mid >> 8) as u8,
-            time_mid as u8,
-            (time_high_and_version >> 8) as u8,
-            time_high_and_version as u8,
-            (((timestamp.counter & 0x3F00) >> 8) as u8) | 0x80,
-            (timestamp.counter & 0xFF) as u8,
-            mac[0],
-            mac[1],
-            mac[2],
-            mac[3],
-            mac[4],
-            mac[5]
-        ])
+    #[inline]
+    ///Generates UUID from time and mac address, same as `v1`, but fails if `timestamp` ticks
+    ///exceed 60 bits instead of discarding excess.
+    pub const fn v1_checked(timestamp: Timestamp, mac: [u8; 6]) -> Result<Self, FieldsError> {
+        match timestamp.ticks > V1_MAX_TICKS {
+            true => Err(FieldsError::TimestampOverflow(timestamp.ticks)),
+            false => Ok(Self::v1(timestamp, mac)),
+        }
+    }
+
+    #[inline(always)]
+    ///Generates UUID from time and arbitrary `node`, same as `v1`.
+    ///
+    ///Meant to make it clear that `node` is not necessary MAC address.
+    pub const fn v1_with_node_bytes(timestamp: Timestamp, node: [u8; 6]) -> Self {
+        Self::v1(timestamp, node)
+    }
+
+    ///Generates UUID from time and random `node`, produced by `rng`.
+    ///
+    ///Multicast bit is set in `node`, as per RFC4122, to avoid collision with MAC addresses.
+    pub fn v1_random_from<R: Rng>(timestamp: Timestamp, rng: &mut R) -> Self {
+        let mut node = [0; 6];
+        rng.fill_bytes(&mut node);
+        node[0] |= 0x01;
+        Self::v1(timestamp, node)
+    }
+
+    #[cfg(any(feature = "sha1", feature = "sha1-compact"))]
+    ///Generates UUID from time and `node`, derived by hashing `seed` (e.g. hostname) with `sha1`.
+    ///
+    ///This gives stable `node` without exposing MAC address.
+    ///Multicast bit is set in `node`, as per RFC4122, to avoid collision with MAC addresses.
+    ///
+    ///Only available when `sha1` or `sha1-compact` feature is enabled.
+    pub const fn v1_hashed_node(timestamp: Timestamp, seed: &[u8]) -> Self {
+        let sha1 = Sha1::new().const_update(seed).const_result();
+        Self::v1(timestamp, [sha1[0] | 0x01, sha1[1], sha1[2], sha1[3], sha1[4], sha1[5]])
     }
 
     #[cfg(feature = "md5")]
@@ -322,14 +862,21 @@ impl Uuid {
     ///
     ///Only available when `md5` feature is enabled.
     pub const fn v3(namespace: Uuid, name: &[u8]) -> Self {
+        Self::v3_with_digest(namespace, name).0
+    }
+
+    #[cfg(feature = "md5")]
+    ///Generates UUID `v3` by using `md5` hasher, returning it alongside `md5` digest.
+    ///
+    ///Digest is the same as UUID bytes, except for version and variant bits.
+    ///
+    ///Only available when `md5` feature is enabled.
+    pub const fn v3_with_digest(namespace: Uuid, name: &[u8]) -> (Self, [u8; 16]) {
         let hash = lhash::Md5::new().const_update(&namespace.data)
                                     .const_update(name)
                                     .const_result();
 
-        Self::from_bytes([
-            hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7],
-            hash[8], hash[9], hash[10], hash[11], hash[12], hash[13], hash[14], hash[15],
-        ]).set_variant().set_version(Version::Md5)
+        (Self::from_bytes(hash).set_variant().set_version(Version::Md5), hash)
     }
 
     #[inline]
@@ -342,17 +889,77 @@ impl Uuid {
         Self::from_bytes(random).set_variant().set_version(Version::Random)
     }
 
+    ///Constructs UUID `v4` from `seed`, expanding it to 16 bytes via `splitmix64`.
+    ///
+    ///Same `seed` always results in the same UUID, which is useful for test fixtures.
+    ///Not suitable when UUID must be unpredictable.
+    pub const fn v4_from_seed(seed: u64) -> Self {
+        const fn splitmix64(state: u64) -> (u64, u64) {
+            let state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut result = state;
+            result = (result ^ (result >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            result = (result ^ (result >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            (state, result ^ (result >> 31))
+        }
+
+        let (state, high) = splitmix64(seed);
+        let (_, low) = splitmix64(state);
+        Self::v4_from((((high as u128) << 64) | (low as u128)).to_be_bytes())
+    }
+
+    #[inline(always)]
+    ///Returns number of random bits in UUID `v4`.
+    ///
+    ///Out of 128 bits, 4 are taken by version and 2 by variant, the rest is preserved as it is
+    ///and therefore setting these bits doesn't skew distribution of random bits.
+    pub const fn v4_entropy_bits() -> usize {
+        UUID_SIZE * 8 - 4 - 2
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    ///Estimates probability of at least one collision among `count` UUIDs `v4`.
+    ///
+    ///Uses birthday bound approximation `1 - e^(-n^2 / 2^123)` over 122 random bits.
+    ///
+    ///Only available when `std` or `libm` feature is enabled.
+    pub fn collision_probability(count: u64) -> f64 {
+        let count = count as f64;
+        let space = (1u128 << Self::v4_entropy_bits()) as f64;
+        let exponent = -(count * count) / (2.0 * space);
+
+        #[cfg(feature = "std")]
+        let result = -exponent.exp_m1();
+        #[cfg(not(feature = "std"))]
+        let result = -libm::expm1(exponent);
+
+        result
+    }
+
     #[cfg(feature = "osrng")]
     ///Generates UUID `v4` using OS RNG from [getrandom](https://crates.io/crates/getrandom)
     ///
     ///Only available when `osrng` feature is enabled.
     pub fn v4() -> Self {
-        let mut bytes = [0; UUID_SIZE];
-        if let Err(error) = getrandom::getrandom(&mut bytes[..]) {
-            panic!("OS RNG is not available for use: {}", error)
-        }
+        Self::v4_from(Self::random_bytes())
+    }
+
+    #[cfg(feature = "osrng")]
+    ///Returns 16 random bytes from the same OS RNG as used by `v4`, without setting version or
+    ///variant.
+    ///
+    ///Only available when `osrng` feature is enabled.
+    pub fn random_bytes() -> [u8; UUID_SIZE] {
+        Self::random_bytes_from(&mut OsRng)
+    }
 
-        Self::v4_from(bytes)
+    #[inline]
+    ///Returns 16 random bytes from provided `rng`, without setting version or variant.
+    ///
+    ///With seeded `rng` (e.g. `wy::Random`) result is reproducible.
+    pub fn random_bytes_from<R: Rng>(rng: &mut R) -> [u8; UUID_SIZE] {
+        let mut bytes = [0; UUID_SIZE];
+        rng.fill_bytes(&mut bytes);
+        bytes
     }
 
     #[cfg(feature = "prng")]
@@ -368,25 +975,209 @@ impl Uuid {
     ///This random is useful when you want to generate predictable but unique UUIDs
     ///Otherwise use `v4`
     pub fn prng() -> Self {
+        Self::v4_from(Self::prng_bytes())
+    }
+
+    #[cfg(feature = "prng")]
+    ///Creates infinite iterator of UUIDs `v4`, generated by PRNG from [wyhash](https://crates.io/crates/wy)
+    ///initialized with `seed`.
+    ///
+    ///Only available when `prng` feature is enabled.
+    ///
+    ///Unlike `prng`, state is local to iterator, hence the same `seed` always produces the same
+    ///sequence, which is useful for reproducible tests.
+    pub fn seeded_sequence(seed: u64) -> impl Iterator<Item = Self> {
+        let mut random = wy::Random::new(seed);
+        core::iter::from_fn(move || {
+            let mut bytes = [0; UUID_SIZE];
+            random.fill_bytes(&mut bytes);
+            Some(Self::v4_from(bytes))
+        })
+    }
+
+    #[cfg(feature = "prng")]
+    ///Returns 16 random bytes from the same PRNG as used by `prng`, without setting version or
+    ///variant.
+    ///
+    ///Only available when `prng` feature is enabled.
+    ///
+    ///PRNG is shared with `prng`, hence sequence is predictable only as a whole.
+    pub fn prng_bytes() -> [u8; UUID_SIZE] {
         static RANDOM: wy::AtomicRandom = wy::AtomicRandom::new(9);
         let right = u128::from(RANDOM.gen());
         let left = u128::from(RANDOM.gen());
-        Self::v4_from(((left << 64) |  right).to_ne_bytes())
+        ((left << 64) |  right).to_ne_bytes()
     }
 
-    #[cfg(feature = "sha1")]
-    ///Generates UUID `v5` by using `sha1` hasher
+    ///Constructs UUID `v7` from `timestamp`, storing sub-millisecond precision instead of random
+    ///in `rand_a`, as per RFC9562 section 6.2 method 3, while `random` is used as `rand_b`.
     ///
-    ///Only available when `sha1` feature is enabled.
-    pub const fn v5(namespace: Uuid, name: &[u8]) -> Self {
-        let sha1 = lhash::Sha1::new().const_update(&namespace.data)
-                                     .const_update(name)
-                                     .const_result();
+    ///Fraction of millisecond is scaled to 12 bits, which gives precision of ~244 nanoseconds,
+    ///while `Timestamp` itself has precision of 100 nanoseconds.
+    ///This improves ordering of UUIDs generated within the same millisecond, at the cost of
+    ///leaving only 62 random bits.
+    ///
+    ///Timestamp before unix epoch is treated as unix epoch.
+    pub const fn v7_precise_from(timestamp: Timestamp, random: [u8; 8]) -> Self {
+        let unix = timestamp.ticks.saturating_sub(V1_NS_TICKS);
+        let millis = (unix / 10_000).to_be_bytes();
+        let fraction = (((unix % 10_000) * 4096 / 10_000) as u16).to_be_bytes();
 
         Self::from_bytes([
-            sha1[0], sha1[1], sha1[2], sha1[3], sha1[4], sha1[5], sha1[6], sha1[7],
-            sha1[8], sha1[9], sha1[10], sha1[11], sha1[12], sha1[13], sha1[14], sha1[15],
-        ]).set_variant().set_version(Version::Sha1)
+            millis[2], millis[3], millis[4], millis[5], millis[6], millis[7],
+            fraction[0], fraction[1],
+            random[0], random[1], random[2], random[3], random[4], random[5], random[6], random[7],
+        ]).set_variant().with_version_number(7)
+    }
+
+    #[cfg(feature = "osrng")]
+    ///Generates UUID `v7` with sub-millisecond precision, using OS RNG for `rand_b`.
+    ///
+    ///Refer to `v7_precise_from` for details.
+    ///
+    ///Only available when `osrng` feature is enabled.
+    pub fn v7_precise(timestamp: Timestamp) -> Self {
+        let mut random = [0; 8];
+        OsRng.fill_bytes(&mut random);
+        Self::v7_precise_from(timestamp, random)
+    }
+
+    #[cfg(all(feature = "std", feature = "osrng"))]
+    ///Fills `out` with strictly increasing UUIDs `v7`, using current time.
+    ///
+    ///Time is read once, while 12-bit counter is stored in `rand_a`, as per RFC9562 section 6.2
+    ///method 1. On counter overflow, timestamp is incremented by one millisecond.
+    ///Random `rand_b` is generated by single call to OS RNG for the whole slice.
+    ///
+    ///Only available when `std` and `osrng` features are enabled.
+    pub fn v7_fill(out: &mut [Uuid]) {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("System time is behind unix epoch");
+        let mut millis = now.as_millis() as u64;
+
+        //Uuid is transparent wrapper over bytes, hence slice can be filled at once
+        let bytes = unsafe {
+            core::slice::from_raw_parts_mut(out.as_mut_ptr() as *mut u8, out.len() * UUID_SIZE)
+        };
+        OsRng.fill_bytes(bytes);
+
+        let mut counter = 0u16;
+        for uuid in out.iter_mut() {
+            if counter > 0x0FFF {
+                counter = 0;
+                millis += 1;
+            }
+
+            let time = millis.to_be_bytes();
+            let counter_bytes = counter.to_be_bytes();
+            let data = &mut uuid.data;
+            data[..6].copy_from_slice(&time[2..]);
+            data[6] = counter_bytes[0];
+            data[7] = counter_bytes[1];
+            *uuid = uuid.set_variant().with_version_number(7);
+
+            counter += 1;
+        }
+    }
+
+    #[cfg(any(feature = "sha1", feature = "sha1-compact"))]
+    ///Generates UUID `v5` by using `sha1` hasher
+    ///
+    ///Only available when `sha1` or `sha1-compact` feature is enabled.
+    pub const fn v5(namespace: Uuid, name: &[u8]) -> Self {
+        Self::v5_with_digest(namespace, name).0
+    }
+
+    #[cfg(any(feature = "sha1", feature = "sha1-compact"))]
+    #[inline]
+    ///Generates UUID `v5` by using `sha1` hasher, same as `v5`, but fails if `namespace` is `nil`,
+    ///which is usually accidental default rather than intended namespace.
+    ///
+    ///Use `v5` to generate UUID within `nil` namespace deliberately.
+    ///
+    ///Only available when `sha1` or `sha1-compact` feature is enabled.
+    pub const fn v5_checked(namespace: Uuid, name: &[u8]) -> Result<Self, GenerateError> {
+        match namespace.is_nil() {
+            true => Err(GenerateError::NilNamespace),
+            false => Ok(Self::v5(namespace, name)),
+        }
+    }
+
+    #[cfg(any(feature = "sha1", feature = "sha1-compact"))]
+    ///Generates UUID `v5` by using `sha1` hasher, returning it alongside full `sha1` digest.
+    ///
+    ///UUID is made of first 16 bytes of digest, with version and variant bits set.
+    ///
+    ///Only available when `sha1` or `sha1-compact` feature is enabled.
+    pub const fn v5_with_digest(namespace: Uuid, name: &[u8]) -> (Self, [u8; 20]) {
+        let sha1 = Sha1::new().const_update(&namespace.data)
+                              .const_update(name)
+                              .const_result();
+
+        (Self::from_sha1(&sha1), sha1)
+    }
+
+    #[cfg(any(feature = "sha1", feature = "sha1-compact"))]
+    ///Generates UUID `v5` by using `sha1` hasher, with name supplied in chunks.
+    ///
+    ///Result is the same as `v5` with concatenation of all chunks as name.
+    ///
+    ///Only available when `sha1` or `sha1-compact` feature is enabled.
+    pub fn v5_from_iter<I: IntoIterator>(namespace: Uuid, chunks: I) -> Self where I::Item: AsRef<[u8]> {
+        let mut sha1 = Sha1::new().const_update(&namespace.data);
+        for chunk in chunks {
+            sha1 = sha1.const_update(chunk.as_ref());
+        }
+
+        Self::from_sha1(&sha1.const_result())
+    }
+
+    #[cfg(any(feature = "sha1", feature = "sha1-compact"))]
+    #[inline(always)]
+    const fn from_sha1(sha1: &[u8; 20]) -> Self {
+        Self::from_bytes([
+            sha1[0], sha1[1], sha1[2], sha1[3], sha1[4], sha1[5], sha1[6], sha1[7],
+            sha1[8], sha1[9], sha1[10], sha1[11], sha1[12], sha1[13], sha1[14], sha1[15],
+        ]).set_variant().set_version(Version::Sha1)
+    }
+
+    ///Generates UUID of specified `version`, using provided inputs.
+    ///
+    ///Fails if input required by `version` is missing or `version` is not supported within
+    ///enabled features:
+    ///
+    ///- `Mac` requires `timestamp` and `node`;
+    ///- `Md5` requires `md5` feature, `namespace` and `name`;
+    ///- `Random` requires `osrng` or `prng` feature, preferring `osrng` if both are enabled;
+    ///- `Sha1` requires `sha1` or `sha1-compact` feature, `namespace` and `name`;
+    ///- `Dce` is not supported.
+    pub fn generate(version: Version, opts: GenerateOpts<'_>) -> Result<Self, GenerateError> {
+        match version {
+            Version::Nil => Ok(Self::nil()),
+            Version::Mac => match (opts.timestamp, opts.node) {
+                (Some(timestamp), Some(node)) => Ok(Self::v1(timestamp, node)),
+                (None, _) => Err(GenerateError::MissingTimestamp),
+                (_, None) => Err(GenerateError::MissingNode),
+            },
+            #[cfg(feature = "md5")]
+            Version::Md5 => match (opts.namespace, opts.name) {
+                (Some(namespace), Some(name)) => Ok(Self::v3(namespace, name)),
+                (None, _) => Err(GenerateError::MissingNamespace),
+                (_, None) => Err(GenerateError::MissingName),
+            },
+            #[cfg(feature = "osrng")]
+            Version::Random => Ok(Self::v4()),
+            #[cfg(all(feature = "prng", not(feature = "osrng")))]
+            Version::Random => Ok(Self::prng()),
+            #[cfg(any(feature = "sha1", feature = "sha1-compact"))]
+            Version::Sha1 => match (opts.namespace, opts.name) {
+                (Some(namespace), Some(name)) => Ok(Self::v5(namespace, name)),
+                (None, _) => Err(GenerateError::MissingNamespace),
+                (_, None) => Err(GenerateError::MissingName),
+            },
+            version => Err(GenerateError::Unsupported(version)),
+        }
     }
 
     #[inline]
@@ -395,18 +1186,115 @@ impl Uuid {
     ///This implementation only cares about RFC4122, there is no option to set other variant.
     ///
     ///Useful when user is supplied with random bytes, and wants to create UUID from it.
-    pub const fn set_variant(mut self) -> Self {
-        self.data[8] = (self.data[8] & 0x3f) | 0x80;
-        self
+    pub const fn set_variant(self) -> Self {
+        Builder::from_bytes(self.data).set_variant().build()
     }
 
     #[inline]
     ///Adds version byte to the corresponding field.
     ///
     ///Useful when user is supplied with random bytes, and wants to create UUID from it.
-    pub const fn set_version(mut self, version: Version) -> Self {
-        self.data[6] = (self.data[6] & 0x0f) | ((version as u8) << 4);
-        self
+    pub const fn set_version(self, version: Version) -> Self {
+        Builder::from_bytes(self.data).set_version(version).build()
+    }
+
+    #[inline]
+    ///Writes low 4 bits of `version` as version number.
+    ///
+    ///Unlike `set_version` it allows to use versions not present in `Version`.
+    pub const fn with_version_number(self, version: u8) -> Self {
+        Builder::from_bytes(self.data).set_version_number(version).build()
+    }
+
+    #[inline]
+    ///Re-applies variant and `version` bits.
+    ///
+    ///Useful after modifying raw bytes via `map_bytes`, which doesn't preserve them.
+    pub const fn re_stamp(self, version: Version) -> Self {
+        self.set_variant().set_version(version)
+    }
+
+    #[inline]
+    ///Creates new instance by transforming raw bytes with provided closure.
+    ///
+    ///Note that variant and version are not preserved, use `re_stamp` to restore them if needed.
+    pub fn map_bytes<F: FnOnce([u8; UUID_SIZE]) -> [u8; UUID_SIZE]>(self, cb: F) -> Self {
+        Self::from_bytes(cb(self.data))
+    }
+
+    const fn parse_hyphenated_ascii_bytes(input: &[u8], offset: usize) -> Result<Self, ParseError> {
+        if input.len() < offset + StrBuf::capacity() {
+            return Err(ParseError::InvalidLength(input.len()));
+        }
+
+        //Non-ASCII character in place of `-` (e.g. en-dash) is reported as it is
+        let separators = [offset + 8, offset + 13, offset + 18, offset + 23];
+        let mut idx = 0;
+        while idx < separators.len() {
+            let byte = input[separators[idx]];
+            if !byte.is_ascii() {
+                return Err(ParseError::InvalidByte(byte, separators[idx]));
+            }
+            idx += 1;
+        }
+
+        if input[offset + 8] != SEP {
+            return Err(ParseError::InvalidGroup(1));
+        } else if input[offset + 13] != SEP {
+            return Err(ParseError::InvalidGroup(2));
+        } else if input[offset + 18] != SEP {
+            return Err(ParseError::InvalidGroup(3));
+        } else if input[offset + 23] != SEP {
+            return Err(ParseError::InvalidGroup(4));
+        }
+
+        Ok(Self::from_bytes([
+            hex_to_byte_try!(input, offset),
+            hex_to_byte_try!(input, offset + 2),
+            hex_to_byte_try!(input, offset + 4),
+            hex_to_byte_try!(input, offset + 6),
+            //+1 for `-`
+            hex_to_byte_try!(input, offset + 8 + 1),
+            hex_to_byte_try!(input, offset + 10 + 1),
+            //+1 for `-`
+            hex_to_byte_try!(input, offset + 12 + 2),
+            hex_to_byte_try!(input, offset + 14 + 2),
+            //+1 for `-`
+            hex_to_byte_try!(input, offset + 16 + 3),
+            hex_to_byte_try!(input, offset + 18 + 3),
+            //+1 for `-`
+            hex_to_byte_try!(input, offset + 20 + 4),
+            hex_to_byte_try!(input, offset + 22 + 4),
+            hex_to_byte_try!(input, offset + 24 + 4),
+            hex_to_byte_try!(input, offset + 26 + 4),
+            hex_to_byte_try!(input, offset + 28 + 4),
+            hex_to_byte_try!(input, offset + 30 + 4),
+        ]))
+    }
+
+    const fn parse_simple_ascii_bytes(input: &[u8], offset: usize) -> Result<Self, ParseError> {
+        if input.len() < offset + UUID_SIZE * 2 {
+            return Err(ParseError::InvalidLength(input.len()));
+        }
+
+        Ok(Self::from_bytes([
+            hex_to_byte_try!(input, offset),
+            hex_to_byte_try!(input, offset + 2),
+            hex_to_byte_try!(input, offset + 4),
+            hex_to_byte_try!(input, offset + 6),
+            hex_to_byte_try!(input, offset + 8),
+            hex_to_byte_try!(input, offset + 10),
+            hex_to_byte_try!(input, offset + 12),
+            hex_to_byte_try!(input, offset + 14),
+            hex_to_byte_try!(input, offset + 16),
+            hex_to_byte_try!(input, offset + 18),
+            hex_to_byte_try!(input, offset + 20),
+            hex_to_byte_try!(input, offset + 22),
+            hex_to_byte_try!(input, offset + 24),
+            hex_to_byte_try!(input, offset + 26),
+            hex_to_byte_try!(input, offset + 28),
+            hex_to_byte_try!(input, offset + 30),
+        ]))
     }
 
     ///Creates new instance by parsing provided bytes.
@@ -415,119 +1303,604 @@ impl Uuid {
     ///As long as supplied bytes contain valid ascii characters it will parse successfully.
     ///Otherwise it shall fail with invalid character.
     ///
-    ///Supports only simple sequence of characters and `-` separated.
+    ///Supports only simple sequence of characters (optionally prefixed with `0x`) and `-` separated
+    ///(optionally prefixed with `urn:uuid:`, including percent-encoded `urn%3Auuid%3A`).
+    ///
+    ///When length doesn't match any form, error is reported in following order:
+    ///- `InvalidByte` for first non-ASCII character;
+    ///- `InvalidGroupLen` for first group of unexpected length, if input has 5 groups separated by `-`;
+    ///- `InvalidLength` otherwise.
+    ///
+    ///Parsing never panics, regardless of input, hence it is safe to use with untrusted input.
     pub const fn parse_ascii_bytes(input: &[u8]) -> Result<Self, ParseError> {
         if input.len() == StrBuf::capacity() {
-            if input[8] != SEP {
-                return Err(ParseError::InvalidGroup(1));
-            } else if input[13] != SEP {
-                return Err(ParseError::InvalidGroup(2));
-            } else if input[18] != SEP {
-                return Err(ParseError::InvalidGroup(3));
-            } else if input[23] != SEP {
-                return Err(ParseError::InvalidGroup(4));
-            }
-
-            Ok(Self::from_bytes([
-                hex_to_byte_try!(input, 0),
-                hex_to_byte_try!(input, 2),
-                hex_to_byte_try!(input, 4),
-                hex_to_byte_try!(input, 6),
-                //+1 for `-`
-                hex_to_byte_try!(input, 8 + 1),
-                hex_to_byte_try!(input, 10 + 1),
-                //+1 for `-`
-                hex_to_byte_try!(input, 12 + 2),
-                hex_to_byte_try!(input, 14 + 2),
-                //+1 for `-`
-                hex_to_byte_try!(input, 16 + 3),
-                hex_to_byte_try!(input, 18 + 3),
-                //+1 for `-`
-                hex_to_byte_try!(input, 20 + 4),
-                hex_to_byte_try!(input, 22 + 4),
-                hex_to_byte_try!(input, 24 + 4),
-                hex_to_byte_try!(input, 26 + 4),
-                hex_to_byte_try!(input, 28 + 4),
-                hex_to_byte_try!(input, 30 + 4),
-            ]))
+            Self::parse_hyphenated_ascii_bytes(input, 0)
+        } else if input.len() == StrBuf::capacity() + URN_PREFIX.len() && starts_with_ignore_case(input, URN_PREFIX) {
+            Self::parse_hyphenated_ascii_bytes(input, URN_PREFIX.len())
+        } else if input.len() == StrBuf::capacity() + URN_PREFIX_ENCODED.len() && starts_with_ignore_case(input, URN_PREFIX_ENCODED) {
+            Self::parse_hyphenated_ascii_bytes(input, URN_PREFIX_ENCODED.len())
         } else if input.len() == StrBuf::capacity() - 4 {
-            Ok(Self::from_bytes([
-                hex_to_byte_try!(input, 0),
-                hex_to_byte_try!(input, 2),
-                hex_to_byte_try!(input, 4),
-                hex_to_byte_try!(input, 6),
-                hex_to_byte_try!(input, 8),
-                hex_to_byte_try!(input, 10),
-                hex_to_byte_try!(input, 12),
-                hex_to_byte_try!(input, 14),
-                hex_to_byte_try!(input, 16),
-                hex_to_byte_try!(input, 18),
-                hex_to_byte_try!(input, 20),
-                hex_to_byte_try!(input, 22),
-                hex_to_byte_try!(input, 24),
-                hex_to_byte_try!(input, 26),
-                hex_to_byte_try!(input, 28),
-                hex_to_byte_try!(input, 30),
-            ]))
+            Self::parse_simple_ascii_bytes(input, 0)
+        } else if input.len() == StrBuf::capacity() - 2 && input[0] == b'0' && (input[1] == b'x' || input[1] == b'X') {
+            Self::parse_simple_ascii_bytes(input, 2)
         } else {
-            Err(ParseError::InvalidLength(input.len()))
+            //Non-ASCII character (e.g. en-dash instead of `-`) changes length, so report it instead
+            let mut idx = 0;
+            while idx < input.len() {
+                if !input[idx].is_ascii() {
+                    return Err(ParseError::InvalidByte(input[idx], idx));
+                }
+                idx += 1;
+            }
+
+            //Hyphenated input with wrong length, so report group which has wrong length instead
+            let offset = if starts_with_ignore_case(input, URN_PREFIX) {
+                URN_PREFIX.len()
+            } else if starts_with_ignore_case(input, URN_PREFIX_ENCODED) {
+                URN_PREFIX_ENCODED.len()
+            } else {
+                0
+            };
+            match Self::find_invalid_group_len(input, offset) {
+                Some(error) => Err(error),
+                None => Err(ParseError::InvalidLength(input.len())),
+            }
         }
     }
 
+    //Returns first group with unexpected length, if `input` consists of 5 groups separated by `-`
+    const fn find_invalid_group_len(input: &[u8], offset: usize) -> Option<ParseError> {
+        const GROUP_LENS: [usize; 5] = [8, 4, 4, 4, 12];
+
+        let mut separators = 0;
+        let mut idx = offset;
+        while idx < input.len() {
+            if input[idx] == SEP {
+                separators += 1;
+            }
+            idx += 1;
+        }
+
+        if separators != GROUP_LENS.len() - 1 {
+            return None;
+        }
+
+        let mut group = 0;
+        let mut start = offset;
+        idx = offset;
+        while idx <= input.len() {
+            if idx == input.len() || input[idx] == SEP {
+                if idx - start != GROUP_LENS[group] {
+                    return Some(ParseError::InvalidGroupLen(group as u8 + 1, start, idx - start));
+                }
+                group += 1;
+                start = idx + 1;
+            }
+            idx += 1;
+        }
+
+        None
+    }
+
     #[inline(always)]
     ///Creates new instance by parsing provided string.
     ///
-    ///Supports only simple sequence of characters and `-` separated.
+    ///Supports only simple sequence of characters (optionally prefixed with `0x`) and `-` separated
+    ///(optionally prefixed with `urn:uuid:`, including percent-encoded `urn%3Auuid%3A`).
+    ///
+    ///Never panics, refer to `parse_ascii_bytes` for details.
     pub const fn parse_str(input: &str) -> Result<Self, ParseError> {
         Self::parse_ascii_bytes(input.as_bytes())
     }
 
     #[inline]
-    ///Creates textual representation of UUID in a static buffer.
+    ///Lazily parses UUIDs from `input`, separated by `sep`.
+    ///
+    ///Each token is parsed as `parse_str`, hence empty token results in `InvalidLength(0)`.
+    pub fn parse_iter(input: &str, sep: char) -> impl Iterator<Item = Result<Self, ParseError>> + '_ {
+        input.split(sep).map(Self::parse_str)
+    }
+
+    #[inline]
+    ///Creates new instance by parsing provided string, ignoring single trailing NUL character.
+    ///
+    ///Useful for strings coming from C, where buffer includes NUL terminator.
+    ///Otherwise it is the same as `parse_str`, hence any other NUL is rejected.
+    pub const fn parse_cstr(input: &str) -> Result<Self, ParseError> {
+        match input.as_bytes().split_last() {
+            Some((0, input)) => Self::parse_ascii_bytes(input),
+            _ => Self::parse_ascii_bytes(input.as_bytes()),
+        }
+    }
+
+    #[inline(always)]
+    ///Creates new instance by parsing provided string, same as `parse_str`.
+    ///
+    ///Never panics, refer to `parse_ascii_bytes` for details.
+    pub const fn try_parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_ascii_bytes(input.as_bytes())
+    }
+
+    ///Parses UUID from list of 16 hex bytes, separated by spaces and/or commas (e.g. `6b a7 b8 10 ...`).
+    ///
+    ///Errors:
+    ///- `InvalidLength` with number of tokens, if it is not 16;
+    ///- `InvalidGroupLen` with token number (from 1), position and length, if token is not 2 characters;
+    ///- `InvalidByte` with position in `input`, if token is not hex.
+    pub const fn parse_byte_list(input: &str) -> Result<Self, ParseError> {
+        const fn is_sep(byte: u8) -> bool {
+            byte == b' ' || byte == b','
+        }
+
+        let input = input.as_bytes();
+        let mut data = [0u8; UUID_SIZE];
+        let mut count = 0;
+        let mut cursor = 0;
+
+        while cursor < input.len() {
+            if is_sep(input[cursor]) {
+                cursor += 1;
+                continue;
+            }
+
+            let start = cursor;
+            while cursor < input.len() && !is_sep(input[cursor]) {
+                cursor += 1;
+            }
+
+            if count < UUID_SIZE {
+                if cursor - start != 2 {
+                    return Err(ParseError::InvalidGroupLen(count as u8 + 1, start, cursor - start));
+                }
+                data[count] = hex_to_byte_try!(input, start);
+            }
+            count += 1;
+        }
+
+        if count != UUID_SIZE {
+            return Err(ParseError::InvalidLength(count));
+        }
+
+        Ok(Self::from_bytes(data))
+    }
+
+    ///Converts simple form of UUID into hyphenated form, without parsing it.
+    ///
+    ///Characters are copied as they are, hence case is preserved.
+    ///Fails if `input` is not 32 hex characters.
+    pub fn hyphenate_simple(input: &str, out: &mut [u8; StrBuf::capacity()]) -> Result<(), ParseError> {
+        let input = input.as_bytes();
+        if input.len() != UUID_SIZE * 2 {
+            return Err(ParseError::InvalidLength(input.len()));
+        }
+
+        let mut cursor = 0;
+        for (idx, chr) in input.iter().enumerate() {
+            if !chr.is_ascii_hexdigit() {
+                return Err(ParseError::InvalidByte(*chr, idx));
+            } else if idx == 8 || idx == 12 || idx == 16 || idx == 20 {
+                out[cursor] = SEP;
+                cursor += 1;
+            }
+
+            out[cursor] = *chr;
+            cursor += 1;
+        }
+
+        Ok(())
+    }
+
+    ///Parses UUID at the start of `input`, ignoring the rest.
+    ///
+    ///Returns UUID and number of consumed bytes (36 for hyphenated form and 32 for simple form).
+    pub const fn parse_prefix(input: &str) -> Result<(Self, usize), ParseError> {
+        let input = input.as_bytes();
+        if input.len() > 8 && input[8] == SEP {
+            if input.len() < StrBuf::capacity() {
+                return Err(ParseError::InvalidLength(input.len()));
+            }
+
+            match Self::parse_hyphenated_ascii_bytes(input, 0) {
+                Ok(uuid) => Ok((uuid, StrBuf::capacity())),
+                Err(error) => Err(error),
+            }
+        } else if input.len() >= UUID_SIZE * 2 {
+            match Self::parse_simple_ascii_bytes(input, 0) {
+                Ok(uuid) => Ok((uuid, UUID_SIZE * 2)),
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(ParseError::InvalidLength(input.len()))
+        }
+    }
+
+    #[inline]
+    ///Returns bytes ordered in a way that their lexicographical order matches order of generation
+    ///for time based UUIDs.
+    ///
+    ///For `v1` timestamp fields are re-arranged from most to least significant, as `time_low`
+    ///comes first within UUID.
+    ///All other versions are returned as they are.
+    pub const fn sort_key(&self) -> [u8; UUID_SIZE] {
+        let data = &self.data;
+        match self.is_version(Version::Mac) {
+            true => [
+                data[6], data[7], data[4], data[5], data[0], data[1], data[2], data[3],
+                data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15],
+            ],
+            false => self.data,
+        }
+    }
+
+    #[inline]
+    ///Creates textual representation of `sort_key` in hyphenated form.
+    ///
+    ///Useful as text key in databases, as lexicographical order of result matches order of
+    ///generation for time based UUIDs.
+    ///Note that bytes of `v1` are reordered, hence parsing it back doesn't yield the original UUID for `v1`.
+    pub const fn sortable_string(&self) -> StrBuf {
+        Self::from_bytes(self.sort_key()).to_str()
+    }
+
+    #[inline(always)]
+    ///Wraps UUID to be formatted and parsed in simple form.
+    pub const fn simple(self) -> Simple {
+        Simple(self)
+    }
+
+    #[inline(always)]
+    ///Wraps UUID to be formatted and parsed in hyphenated form.
+    pub const fn hyphenated(self) -> Hyphenated {
+        Hyphenated(self)
+    }
+
+    #[inline]
+    ///Creates textual representation of UUID in a static buffer, using lower case hex.
     pub const fn to_str(&self) -> StrBuf {
+        self.to_str_case::<false>()
+    }
+
+    #[inline]
+    ///Creates textual representation of UUID in a static buffer, using upper case hex.
+    pub const fn to_str_upper(&self) -> StrBuf {
+        self.to_str_case::<true>()
+    }
+
+    #[inline]
+    ///Creates textual representation of UUID in a static buffer, using upper case hex if `UPPER`
+    ///is `true`.
+    pub const fn to_str_case<const UPPER: bool>(&self) -> StrBuf {
         let storage = [
-            mem::MaybeUninit::new(byte_to_hex(self.data[0], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[0], 0)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[1], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[1], 0)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[2], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[2], 0)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[3], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[3], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[0], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[0], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[1], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[1], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[2], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[2], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[3], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[3], 0)),
             mem::MaybeUninit::new(SEP),
-            mem::MaybeUninit::new(byte_to_hex(self.data[4], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[4], 0)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[5], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[5], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[4], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[4], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[5], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[5], 0)),
             mem::MaybeUninit::new(SEP),
-            mem::MaybeUninit::new(byte_to_hex(self.data[6], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[6], 0)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[7], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[7], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[6], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[6], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[7], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[7], 0)),
             mem::MaybeUninit::new(SEP),
-            mem::MaybeUninit::new(byte_to_hex(self.data[8], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[8], 0)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[9], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[9], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[8], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[8], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[9], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[9], 0)),
             mem::MaybeUninit::new(SEP),
-            mem::MaybeUninit::new(byte_to_hex(self.data[10], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[10], 0)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[11], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[11], 0)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[12], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[12], 0)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[13], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[13], 0)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[14], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[14], 0)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[15], 1)),
-            mem::MaybeUninit::new(byte_to_hex(self.data[15], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[10], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[10], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[11], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[11], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[12], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[12], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[13], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[13], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[14], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[14], 0)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[15], 1)),
+            mem::MaybeUninit::new(byte_to_hex_case::<UPPER>(self.data[15], 0)),
         ];
 
         unsafe {
             StrBuf::from_storage(storage, StrBuf::capacity() as u8)
         }
     }
+
+    const fn simple_bytes(&self) -> [u8; UUID_SIZE * 2] {
+        let mut out = [0; UUID_SIZE * 2];
+        let mut idx = 0;
+        while idx < UUID_SIZE {
+            out[idx * 2] = byte_to_hex(self.data[idx], 1);
+            out[idx * 2 + 1] = byte_to_hex(self.data[idx], 0);
+            idx += 1;
+        }
+
+        out
+    }
+
+    #[inline]
+    ///Formats UUID once, storing result to be displayed without formatting again.
+    pub const fn precompute_display(&self) -> DisplayUuid {
+        let text = self.to_str();
+        let ptr = text.as_ptr();
+        let mut bytes = [0; StrBuf::capacity()];
+        let mut idx = 0;
+        while idx < text.len() {
+            //`idx` is within initialized length of `text`
+            bytes[idx] = unsafe {
+                *ptr.add(idx)
+            };
+            idx += 1;
+        }
+
+        DisplayUuid {
+            bytes,
+        }
+    }
+
+    #[inline]
+    ///Formats UUID in hyphenated form on stack and passes it to `cb`.
+    ///
+    ///Text is borrowed only for duration of `cb`, hence no allocation is necessary.
+    pub fn with_hyphenated<R, F: FnOnce(&str) -> R>(&self, cb: F) -> R {
+        cb(self.to_str().as_str())
+    }
+
+    ///Writes textual representation of UUID in specified `fmt` into `buf`, starting at `offset`.
+    ///
+    ///Returns number of written bytes, or error if `buf` has not enough space after `offset`.
+    pub fn format_at(&self, buf: &mut [u8], offset: usize, fmt: Format) -> Result<usize, ()> {
+        let len = fmt.len();
+        let out = match offset.checked_add(len) {
+            Some(end) if end <= buf.len() => &mut buf[offset..end],
+            _ => return Err(()),
+        };
+
+        match fmt {
+            Format::Hyphenated => out.copy_from_slice(self.to_str().as_slice()),
+            Format::Urn => {
+                out[..URN_PREFIX.len()].copy_from_slice(URN_PREFIX);
+                out[URN_PREFIX.len()..].copy_from_slice(self.to_str().as_slice());
+            },
+            Format::Simple => out.copy_from_slice(&self.simple_bytes()),
+        }
+
+        Ok(len)
+    }
+
+    ///Writes textual representation of UUID into provided buffer, overwriting its content.
+    ///
+    ///Useful to re-use the same buffer when formatting multiple UUIDs.
+    pub fn write_to_str_buf(&self, buf: &mut StrBuf) {
+        *buf = self.to_str();
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+///UUID which can be shared between threads and modified via shared reference.
+///
+///As 128-bit atomics are not available on stable Rust, it relies on `Mutex`.
+///
+///Only available when `std` feature is enabled.
+pub struct AtomicUuid(std::sync::Mutex<u128>);
+
+#[cfg(feature = "std")]
+impl AtomicUuid {
+    #[inline(always)]
+    ///Creates new instance.
+    pub const fn new(uuid: Uuid) -> Self {
+        Self(std::sync::Mutex::new(uuid.as_u128()))
+    }
+
+    #[inline(always)]
+    fn lock(&self) -> std::sync::MutexGuard<'_, u128> {
+        //Value is always valid, hence poisoning can be ignored
+        match self.0.lock() {
+            Ok(guard) => guard,
+            Err(error) => error.into_inner(),
+        }
+    }
+
+    #[inline]
+    ///Loads current value.
+    pub fn load(&self) -> Uuid {
+        Uuid::from_u128(*self.lock())
+    }
+
+    #[inline]
+    ///Stores new value.
+    pub fn store(&self, uuid: Uuid) {
+        *self.lock() = uuid.as_u128();
+    }
+
+    #[inline]
+    ///Stores new value, returning previous one.
+    pub fn swap(&self, uuid: Uuid) -> Uuid {
+        Uuid::from_u128(core::mem::replace(&mut *self.lock(), uuid.as_u128()))
+    }
+
+    #[inline]
+    ///Stores `new` value if current value is equal to `current`.
+    ///
+    ///Returns previous value as `Ok` on success, otherwise current value as `Err`.
+    pub fn compare_exchange(&self, current: Uuid, new: Uuid) -> Result<Uuid, Uuid> {
+        let mut value = self.lock();
+        match *value == current.as_u128() {
+            true => {
+                *value = new.as_u128();
+                Ok(current)
+            },
+            false => Err(Uuid::from_u128(*value)),
+        }
+    }
+
+    #[inline]
+    ///Consumes self, returning current value.
+    pub fn into_inner(self) -> Uuid {
+        let value = match self.0.into_inner() {
+            Ok(value) => value,
+            Err(error) => error.into_inner(),
+        };
+        Uuid::from_u128(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Uuid> for AtomicUuid {
+    #[inline(always)]
+    fn from(uuid: Uuid) -> Self {
+        Self::new(uuid)
+    }
+}
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+///Pre-formatted UUID in hyphenated form, created via `Uuid::precompute_display`.
+pub struct DisplayUuid {
+    bytes: [u8; StrBuf::capacity()],
+}
+
+impl DisplayUuid {
+    #[inline(always)]
+    ///Access textual representation.
+    pub fn as_str(&self) -> &str {
+        unsafe {
+            core::str::from_utf8_unchecked(&self.bytes)
+        }
+    }
+}
+
+impl fmt::Debug for DisplayUuid {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), fmt)
+    }
+}
+
+impl fmt::Display for DisplayUuid {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for DisplayUuid {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+///Builder to construct UUID field by field.
+///
+///Fields are written in big endian, as per RFC4122, while setters of fields sharing bits with
+///version and variant preserve them.
+pub struct Builder([u8; UUID_SIZE]);
+
+impl Builder {
+    #[inline(always)]
+    ///Creates builder with all bits zeroed.
+    pub const fn new() -> Self {
+        Self([0; UUID_SIZE])
+    }
+
+    #[inline(always)]
+    ///Creates builder from raw bytes.
+    pub const fn from_bytes(data: [u8; UUID_SIZE]) -> Self {
+        Self(data)
+    }
+
+    #[inline]
+    ///Sets `time_low` field.
+    pub const fn set_time_low(mut self, time_low: u32) -> Self {
+        let time_low = time_low.to_be_bytes();
+        self.0[0] = time_low[0];
+        self.0[1] = time_low[1];
+        self.0[2] = time_low[2];
+        self.0[3] = time_low[3];
+        self
+    }
+
+    #[inline]
+    ///Sets `time_mid` field.
+    pub const fn set_time_mid(mut self, time_mid: u16) -> Self {
+        let time_mid = time_mid.to_be_bytes();
+        self.0[4] = time_mid[0];
+        self.0[5] = time_mid[1];
+        self
+    }
+
+    #[inline]
+    ///Sets low 12 bits of `time_high` field, preserving version.
+    pub const fn set_time_high(mut self, time_high: u16) -> Self {
+        let time_high = (time_high & 0x0FFF).to_be_bytes();
+        self.0[6] = (self.0[6] & 0xf0) | time_high[0];
+        self.0[7] = time_high[1];
+        self
+    }
+
+    #[inline]
+    ///Sets low 14 bits of `clock_seq` field, preserving variant.
+    pub const fn set_clock_seq(mut self, clock_seq: u16) -> Self {
+        let clock_seq = (clock_seq & 0x3FFF).to_be_bytes();
+        self.0[8] = (self.0[8] & 0xc0) | clock_seq[0];
+        self.0[9] = clock_seq[1];
+        self
+    }
+
+    #[inline]
+    ///Sets `node` field.
+    pub const fn set_node(mut self, node: [u8; 6]) -> Self {
+        self.0[10] = node[0];
+        self.0[11] = node[1];
+        self.0[12] = node[2];
+        self.0[13] = node[3];
+        self.0[14] = node[4];
+        self.0[15] = node[5];
+        self
+    }
+
+    #[inline]
+    ///Sets RFC4122 variant.
+    pub const fn set_variant(mut self) -> Self {
+        self.0[8] = (self.0[8] & 0x3f) | 0x80;
+        self
+    }
+
+    #[inline(always)]
+    ///Sets version.
+    pub const fn set_version(self, version: Version) -> Self {
+        self.set_version_number(version as u8)
+    }
+
+    #[inline]
+    ///Sets low 4 bits of `version` as version number.
+    pub const fn set_version_number(mut self, version: u8) -> Self {
+        self.0[6] = (self.0[6] & 0x0f) | ((version & 0x0f) << 4);
+        self
+    }
+
+    #[inline(always)]
+    ///Creates UUID
+    pub const fn build(self) -> Uuid {
+        Uuid::from_bytes(self.0)
+    }
+}
+
+impl From<Uuid> for Builder {
+    #[inline(always)]
+    fn from(uuid: Uuid) -> Self {
+        Self::from_bytes(uuid.data)
+    }
 }
 
 impl fmt::Display for Uuid {
@@ -537,6 +1910,26 @@ impl fmt::Display for Uuid {
     }
 }
 
+///Formats 128 bits, grouping them into bytes separated by `_`.
+///
+///Alternate flag (`{:#b}`) adds `0b` prefix.
+impl fmt::Binary for Uuid {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if fmt.alternate() {
+            fmt.write_str("0b")?;
+        }
+
+        for (idx, byt) in self.data.iter().enumerate() {
+            if idx > 0 {
+                fmt.write_str("_")?;
+            }
+            fmt.write_fmt(format_args!("{:08b}", byt))?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Default for Uuid {
     #[inline(always)]
     fn default() -> Self {
@@ -558,6 +1951,27 @@ impl From<[u8; UUID_SIZE]> for Uuid {
     }
 }
 
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for Uuid {
+    #[inline(always)]
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        Uuid::ct_eq(self, other)
+    }
+}
+
+#[cfg(feature = "rand")]
+///Generates UUID `v4` from random bytes of `rng`.
+///
+///Only available when `rand` feature is enabled.
+impl rand::distributions::Distribution<Uuid> for rand::distributions::Standard {
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Uuid {
+        let mut bytes = [0; UUID_SIZE];
+        rng.fill_bytes(&mut bytes);
+        Uuid::v4_from(bytes)
+    }
+}
+
 impl core::str::FromStr for Uuid {
     type Err = ParseError;
 
@@ -567,6 +1981,261 @@ impl core::str::FromStr for Uuid {
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[repr(transparent)]
+///UUID in simple textual form, i.e. 32 hex characters without separators.
+///
+///Parsing accepts only simple form.
+pub struct Simple(pub Uuid);
+
+impl fmt::Display for Simple {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let buf = self.0.simple_bytes();
+        fmt.write_str(unsafe {
+            core::str::from_utf8_unchecked(&buf)
+        })
+    }
+}
+
+impl core::str::FromStr for Simple {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.len() == UUID_SIZE * 2 {
+            true => Uuid::parse_str(input).map(Self),
+            false => Err(ParseError::InvalidLength(input.len())),
+        }
+    }
+}
+
+impl From<Uuid> for Simple {
+    #[inline(always)]
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+impl From<Simple> for Uuid {
+    #[inline(always)]
+    fn from(uuid: Simple) -> Self {
+        uuid.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[repr(transparent)]
+///UUID in hyphenated textual form, i.e. 32 hex characters separated by `-` into 5 groups.
+///
+///Parsing accepts only hyphenated form.
+pub struct Hyphenated(pub Uuid);
+
+impl fmt::Display for Hyphenated {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+impl core::str::FromStr for Hyphenated {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.len() == StrBuf::capacity() {
+            true => Uuid::parse_str(input).map(Self),
+            false => Err(ParseError::InvalidLength(input.len())),
+        }
+    }
+}
+
+impl From<Uuid> for Hyphenated {
+    #[inline(always)]
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+impl From<Hyphenated> for Uuid {
+    #[inline(always)]
+    fn from(uuid: Hyphenated) -> Self {
+        uuid.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[repr(transparent)]
+///UUID which is guaranteed to be version 4.
+pub struct V4(Uuid);
+
+impl V4 {
+    #[inline]
+    ///Wraps `uuid`, if its version is 4.
+    pub const fn try_new(uuid: Uuid) -> Option<Self> {
+        match uuid.version_number() == 4 {
+            true => Some(Self(uuid)),
+            false => None,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns underlying UUID.
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl core::ops::Deref for V4 {
+    type Target = Uuid;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for V4 {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+impl From<V4> for Uuid {
+    #[inline(always)]
+    fn from(uuid: V4) -> Self {
+        uuid.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[repr(transparent)]
+///UUID which is guaranteed to be version 7.
+pub struct V7(Uuid);
+
+impl V7 {
+    #[inline]
+    ///Wraps `uuid`, if its version is 7.
+    pub const fn try_new(uuid: Uuid) -> Option<Self> {
+        match uuid.version_number() == 7 {
+            true => Some(Self(uuid)),
+            false => None,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns underlying UUID.
+    pub const fn into_inner(self) -> Uuid {
+        self.0
+    }
+}
+
+impl core::ops::Deref for V7 {
+    type Target = Uuid;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for V7 {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+impl From<V7> for Uuid {
+    #[inline(always)]
+    fn from(uuid: V7) -> Self {
+        uuid.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+///Optional UUID, treating `nil` as absence of value.
+///
+///When `serde` feature is enabled, `None` and `nil` are serialized as `none` (e.g. `null` in JSON),
+///while deserialization of `nil` results in `None`.
+///
+///Comparison and hashing treat `None` and `nil` as the same value.
+pub struct Nullable(pub Option<Uuid>);
+
+impl PartialEq for Nullable {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.uuid() == other.uuid()
+    }
+}
+
+impl Eq for Nullable {}
+
+impl PartialOrd for Nullable {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Nullable {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.uuid().cmp(&other.uuid())
+    }
+}
+
+impl core::hash::Hash for Nullable {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.uuid().hash(state)
+    }
+}
+
+impl Nullable {
+    #[inline]
+    ///Creates new instance, converting `nil` to `None`
+    pub const fn new(uuid: Uuid) -> Self {
+        match uuid.is_nil() {
+            true => Self(None),
+            false => Self(Some(uuid)),
+        }
+    }
+
+    #[inline]
+    ///Returns underlying UUID, substituting `None` with `nil`
+    pub const fn uuid(&self) -> Uuid {
+        match self.0 {
+            Some(uuid) => uuid,
+            None => Uuid::nil(),
+        }
+    }
+}
+
+impl From<Uuid> for Nullable {
+    #[inline(always)]
+    fn from(uuid: Uuid) -> Self {
+        Self::new(uuid)
+    }
+}
+
+impl From<Option<Uuid>> for Nullable {
+    #[inline(always)]
+    fn from(uuid: Option<Uuid>) -> Self {
+        match uuid {
+            Some(uuid) => Self::new(uuid),
+            None => Self(None),
+        }
+    }
+}
+
+impl From<Nullable> for Uuid {
+    #[inline(always)]
+    fn from(uuid: Nullable) -> Self {
+        uuid.uuid()
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 ///Error happening when parsing invalid uuid.
 pub enum ParseError {
@@ -579,13 +2248,22 @@ pub enum ParseError {
     ///Group has invalid len.
     ///
     ///1. Group number;
+    ///2. Position of group start from 0;
     ///3. Actual len;
-    InvalidGroupLen(u8, usize),
+    InvalidGroupLen(u8, usize, usize),
     ///Invalid character is encountered.
     ///
     ///1. Character byte;
     ///2. Position from 0;
-    InvalidByte(u8, usize)
+    InvalidByte(u8, usize),
+    ///Variant is not RFC4122.
+    ///
+    ///1. Actual variant;
+    InvalidVariant(Variant),
+    ///Version is not defined by RFC.
+    ///
+    ///1. Actual version number;
+    InvalidVersion(u8),
 }
 
 impl fmt::Display for ParseError {
@@ -594,15 +2272,76 @@ impl fmt::Display for ParseError {
         match self {
             ParseError::InvalidLength(len) => fmt.write_fmt(format_args!("Invalid length {}", len)),
             ParseError::InvalidGroup(idx) => fmt.write_fmt(format_args!("Group {} has unexpected length", idx)),
-            ParseError::InvalidGroupLen(idx, len) => fmt.write_fmt(format_args!("Group {} has unexpected length {}", idx, len)),
+            ParseError::InvalidGroupLen(idx, pos, len) => fmt.write_fmt(format_args!("Group {} at position {} has unexpected length {}", idx, pos, len)),
             ParseError::InvalidByte(byte, pos) => fmt.write_fmt(format_args!("Invalid character '{:x}' at position {}", byte, pos)),
+            ParseError::InvalidVariant(variant) => fmt.write_fmt(format_args!("Unexpected variant {:?}", variant)),
+            ParseError::InvalidVersion(version) => fmt.write_fmt(format_args!("Unexpected version {}", version)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+///Error happening when creating uuid from inconsistent fields.
+pub enum FieldsError {
+    ///Version within `time_hi_and_version` doesn't match expected one.
+    ///
+    ///1. Actual version number;
+    VersionMismatch(u8),
+    ///Clock sequence doesn't fit 14 bits.
+    ///
+    ///1. Clock sequence;
+    ClockSeqOverflow(u16),
+    ///Timestamp doesn't fit 60 bits.
+    ///
+    ///1. Timestamp ticks;
+    TimestampOverflow(u64),
+}
+
+impl fmt::Display for FieldsError {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldsError::VersionMismatch(version) => fmt.write_fmt(format_args!("Unexpected version {}", version)),
+            FieldsError::ClockSeqOverflow(clock_seq) => fmt.write_fmt(format_args!("Clock sequence {} exceeds 14 bits", clock_seq)),
+            FieldsError::TimestampOverflow(ticks) => fmt.write_fmt(format_args!("Timestamp {} exceeds 60 bits", ticks)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+///Error happening when generating uuid via `Uuid::generate`.
+pub enum GenerateError {
+    ///Version is not supported with current set of features.
+    Unsupported(Version),
+    ///Timestamp is required, but not provided.
+    MissingTimestamp,
+    ///Node is required, but not provided.
+    MissingNode,
+    ///Namespace is required, but not provided.
+    MissingNamespace,
+    ///Name is required, but not provided.
+    MissingName,
+    ///Namespace is `nil`.
+    NilNamespace,
+}
+
+impl fmt::Display for GenerateError {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenerateError::Unsupported(version) => fmt.write_fmt(format_args!("Version {:?} is not supported", version)),
+            GenerateError::MissingTimestamp => fmt.write_str("Timestamp is missing"),
+            GenerateError::MissingNode => fmt.write_str("Node is missing"),
+            GenerateError::MissingNamespace => fmt.write_str("Namespace is missing"),
+            GenerateError::MissingName => fmt.write_str("Name is missing"),
+            GenerateError::NilNamespace => fmt.write_str("Namespace is nil"),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::byte_to_hex;
+    use crate::{byte_to_hex, byte_to_hex_case};
 
     #[test]
     fn should_convert_byte_to_hex() {
@@ -611,5 +2350,7 @@ mod tests {
         assert_eq!([byte_to_hex(1, 1), byte_to_hex(1, 0)], *b"01");
         assert_eq!([byte_to_hex(15, 1), byte_to_hex(15, 0)], *b"0f");
         assert_eq!([byte_to_hex(0, 1), byte_to_hex(0, 0)], *b"00");
+        assert_eq!([byte_to_hex_case::<true>(254, 1), byte_to_hex_case::<true>(254, 0)], *b"FE");
+        assert_eq!([byte_to_hex_case::<true>(10, 1), byte_to_hex_case::<true>(10, 0)], *b"0A");
     }
 }
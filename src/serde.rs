@@ -1,22 +1,21 @@
+//!`serde` support.
+//!
+//!By default human readable formats use textual representation, while binary formats use raw bytes.
+//!
+//!Textual representation preserves byte order, hence time ordered UUIDs (e.g. `v7`) remain
+//!sorted when compared as strings.
+
 use serde::de::{Deserialize, Deserializer};
 use serde::ser::{Serialize, Serializer};
 
-use crate::{UUID_SIZE, Uuid};
+use crate::{Uuid, Nullable};
 
 impl Serialize for Uuid {
     #[inline]
     fn serialize<SER: Serializer>(&self, ser: SER) -> Result<SER::Ok, SER::Error> {
         match ser.is_human_readable() {
             true => ser.serialize_str(&self.to_str()),
-            false => {
-                use serde::ser::SerializeTuple;
-
-                let mut data = ser.serialize_tuple(UUID_SIZE)?;
-                for byt in self.data.iter() {
-                    data.serialize_element(byt)?;
-                }
-                data.end()
-            }
+            false => ser.serialize_bytes(&self.data),
         }
     }
 }
@@ -52,6 +51,14 @@ impl<'de> serde::de::Visitor<'de> for BytesVisitor {
         formatter.write_str("raw uuid bytes with size 16")
     }
 
+    #[inline]
+    fn visit_bytes<E: serde::de::Error>(self, input: &[u8]) -> Result<Self::Value, E> {
+        match Uuid::from_slice(input) {
+            Some(uuid) => Ok(uuid),
+            None => Err(serde::de::Error::invalid_length(input.len(), &self)),
+        }
+    }
+
     #[inline]
     fn visit_seq<S: serde::de::SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
         let bytes = [
@@ -130,17 +137,57 @@ impl<'de> Deserialize<'de> for Uuid {
     fn deserialize<D: Deserializer<'de>>(des: D) -> Result<Self, D::Error> {
         match des.is_human_readable() {
             true => des.deserialize_str(StrVisitor),
-            false => des.deserialize_tuple(UUID_SIZE, BytesVisitor),
+            false => des.deserialize_bytes(BytesVisitor),
         }
     }
 }
 
+impl Serialize for Nullable {
+    #[inline]
+    fn serialize<SER: Serializer>(&self, ser: SER) -> Result<SER::Ok, SER::Error> {
+        match self.0 {
+            Some(uuid) if !uuid.is_nil() => ser.serialize_some(&uuid),
+            _ => ser.serialize_none(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Nullable {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(des: D) -> Result<Self, D::Error> {
+        Option::<Uuid>::deserialize(des).map(Nullable::from)
+    }
+}
+
+pub mod bytes {
+    //!Module to serialize UUID always as raw bytes.
+    //!
+    //!Compatible with `serde_bytes` semantics, for use with `#[serde(with = "lolid::serde::bytes")]`.
+
+    use serde::de::Deserializer;
+    use serde::ser::Serializer;
+
+    use crate::Uuid;
+
+    #[inline]
+    ///Serializes UUID as 16 bytes.
+    pub fn serialize<SER: Serializer>(uuid: &Uuid, ser: SER) -> Result<SER::Ok, SER::Error> {
+        ser.serialize_bytes(&uuid.data)
+    }
+
+    #[inline]
+    ///Deserializes UUID from 16 bytes.
+    pub fn deserialize<'de, D: Deserializer<'de>>(des: D) -> Result<Uuid, D::Error> {
+        des.deserialize_bytes(super::BytesVisitor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::Uuid;
+    use crate::{Uuid, Nullable};
 
     use serde::de::Deserialize;
-    use serde::de::value::{BorrowedStrDeserializer, SeqDeserializer, Error as ValueError};
+    use serde::de::value::{BorrowedStrDeserializer, BytesDeserializer, SeqDeserializer, Error as ValueError};
 
     #[cfg(feature = "osrng")]
     #[test]
@@ -178,6 +225,10 @@ mod tests {
     fn deserialize_array_bincode() {
         let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
         let serialized = bincode::serialize(&uuid).unwrap();
+        //Bytes are prefixed with u64 length
+        assert_eq!(serialized.len(), 24);
+        assert_eq!(serialized[..8], 16u64.to_le_bytes());
+        assert_eq!(&serialized[8..], uuid.as_bytes());
 
         let res: Uuid = bincode::deserialize(&serialized).expect("Unexpected fail");
         assert_eq!(res, uuid);
@@ -188,4 +239,89 @@ mod tests {
         let bytes = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
         bincode::deserialize::<Uuid>(&bytes).unwrap_err();
     }
+
+    #[test]
+    fn serialize_and_deserialize_rmp() {
+        let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+        let serialized = rmp_serde::to_vec(&uuid).unwrap();
+        assert_eq!(serialized.len(), 18);
+        assert_eq!(&serialized[2..], uuid.as_bytes());
+
+        let res: Uuid = rmp_serde::from_slice(&serialized).expect("Unexpected fail");
+        assert_eq!(res, uuid);
+    }
+
+    #[test]
+    fn serialize_and_deserialize_postcard() {
+        let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+        let mut buffer = [0u8; 32];
+        let serialized = postcard::to_slice(&uuid, &mut buffer).unwrap();
+        assert_eq!(serialized.len(), 17);
+        assert_eq!(serialized[0], 16);
+        assert_eq!(&serialized[1..], uuid.as_bytes());
+
+        let res: Uuid = postcard::from_bytes(serialized).expect("Unexpected fail");
+        assert_eq!(res, uuid);
+
+        postcard::from_bytes::<Uuid>(&serialized[..16]).unwrap_err();
+    }
+
+    #[test]
+    fn serialize_and_deserialize_nullable() {
+        let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+
+        assert_eq!(serde_json::to_string(&Nullable(None)).unwrap(), "null");
+        assert_eq!(serde_json::to_string(&Nullable(Some(Uuid::nil()))).unwrap(), "null");
+        assert_eq!(serde_json::to_string(&Nullable(Some(uuid))).unwrap(), "\"60ecb7b6-ba34-5aad-a9ef-9020b1ea210a\"");
+
+        let res: Nullable = serde_json::from_str("\"00000000-0000-0000-0000-000000000000\"").unwrap();
+        assert_eq!(res, Nullable(None));
+        let res: Nullable = serde_json::from_str("\"60ecb7b6-ba34-5aad-a9ef-9020b1ea210a\"").unwrap();
+        assert_eq!(res, Nullable(Some(uuid)));
+        let res: Nullable = serde_json::from_str("null").unwrap();
+        assert_eq!(res, Nullable(None));
+    }
+
+    #[test]
+    fn serialize_v7_preserves_order() {
+        use crate::Timestamp;
+        use core::time::Duration;
+
+        let uuids = [
+            Uuid::v7_precise_from(Timestamp::from_unix(Duration::new(1_496_854_535, 0)), [0xff; 8]),
+            Uuid::v7_precise_from(Timestamp::from_unix(Duration::new(1_496_854_535, 500)), [0; 8]),
+            Uuid::v7_precise_from(Timestamp::from_unix(Duration::new(1_496_854_535, 812_946_000)), [0x10; 8]),
+            Uuid::v7_precise_from(Timestamp::from_unix(Duration::new(1_496_854_536, 0)), [0; 8]),
+            Uuid::v7_precise_from(Timestamp::from_unix(Duration::new(1_700_000_000, 1)), [0x7f; 8]),
+            Uuid::v7_precise_from(Timestamp::from_unix(Duration::new(4_000_000_000, 0)), [0x01; 8]),
+        ];
+        assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let serialized = serde_json::to_value(&uuids).unwrap();
+        let serialized = serialized.as_array().unwrap();
+        assert_eq!(serialized.len(), uuids.len());
+        assert!(serialized.windows(2).all(|pair| pair[0].as_str().unwrap() < pair[1].as_str().unwrap()));
+
+        for (text, uuid) in serialized.iter().zip(uuids.iter()) {
+            assert_eq!(text.as_str().unwrap(), uuid.to_str().as_str());
+        }
+    }
+
+    #[test]
+    fn serialize_and_deserialize_as_bytes() {
+        let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+        let uuid_bytes = uuid.bytes();
+
+        let des = BytesDeserializer::<ValueError>::new(&uuid_bytes);
+        let res = crate::serde::bytes::deserialize(des).expect("Unexpected fail");
+        assert_eq!(res, uuid);
+
+        let des = BytesDeserializer::<ValueError>::new(&uuid_bytes[..15]);
+        crate::serde::bytes::deserialize(des).unwrap_err();
+
+        let serialized = crate::serde::bytes::serialize(&uuid, serde_json::value::Serializer).unwrap();
+        assert_eq!(serialized, serde_json::json!([96, 236, 183, 182, 186, 52, 90, 173, 169, 239, 144, 32, 177, 234, 33, 10]));
+        let res = crate::serde::bytes::deserialize(serialized).expect("Unexpected fail");
+        assert_eq!(res, uuid);
+    }
 }
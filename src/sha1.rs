@@ -0,0 +1,144 @@
+const BLOCK_SIZE: usize = 64;
+const STATE_SIZE: usize = 5;
+const RESULT_SIZE: usize = 20;
+const INIT_STATE: [u32; STATE_SIZE] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+const fn transform(state: [u32; STATE_SIZE], block: &[u8; BLOCK_SIZE]) -> [u32; STATE_SIZE] {
+    let mut w = [0u32; 80];
+    let mut idx = 0;
+    while idx < 16 {
+        w[idx] = u32::from_be_bytes([block[idx * 4], block[idx * 4 + 1], block[idx * 4 + 2], block[idx * 4 + 3]]);
+        idx += 1;
+    }
+    while idx < 80 {
+        w[idx] = (w[idx - 3] ^ w[idx - 8] ^ w[idx - 14] ^ w[idx - 16]).rotate_left(1);
+        idx += 1;
+    }
+
+    let mut a = state[0];
+    let mut b = state[1];
+    let mut c = state[2];
+    let mut d = state[3];
+    let mut e = state[4];
+
+    idx = 0;
+    while idx < 80 {
+        let (f, k) = match idx {
+            0..=19 => (d ^ (b & (c ^ d)), 0x5a827999),
+            20..=39 => (b ^ c ^ d, 0x6ed9eba1),
+            40..=59 => ((b & c) | (d & (b | c)), 0x8f1bbcdc),
+            _ => (b ^ c ^ d, 0xca62c1d6),
+        };
+
+        let tmp = a.rotate_left(5).wrapping_add(f).wrapping_add(e).wrapping_add(k).wrapping_add(w[idx]);
+        e = d;
+        d = c;
+        c = b.rotate_left(30);
+        b = a;
+        a = tmp;
+        idx += 1;
+    }
+
+    [
+        state[0].wrapping_add(a),
+        state[1].wrapping_add(b),
+        state[2].wrapping_add(c),
+        state[3].wrapping_add(d),
+        state[4].wrapping_add(e),
+    ]
+}
+
+pub(crate) struct Sha1 {
+    state: [u32; STATE_SIZE],
+    len: u64,
+    buffer: [u8; BLOCK_SIZE],
+}
+
+impl Sha1 {
+    pub(crate) const fn new() -> Self {
+        Self {
+            state: INIT_STATE,
+            len: 0,
+            buffer: [0; BLOCK_SIZE],
+        }
+    }
+
+    pub(crate) const fn const_update(mut self, input: &[u8]) -> Self {
+        let mut idx = 0;
+        while idx < input.len() {
+            let pos = (self.len % BLOCK_SIZE as u64) as usize;
+            self.buffer[pos] = input[idx];
+            self.len += 1;
+            if pos == BLOCK_SIZE - 1 {
+                self.state = transform(self.state, &self.buffer);
+            }
+            idx += 1;
+        }
+
+        self
+    }
+
+    pub(crate) const fn const_result(mut self) -> [u8; RESULT_SIZE] {
+        let len = self.len.wrapping_shl(3).to_be_bytes();
+
+        self = self.const_update(&[0x80]);
+        while self.len % BLOCK_SIZE as u64 != (BLOCK_SIZE - len.len()) as u64 {
+            self = self.const_update(&[0]);
+        }
+        self = self.const_update(&len);
+
+        let mut result = [0; RESULT_SIZE];
+        let mut idx = 0;
+        while idx < STATE_SIZE {
+            let word = self.state[idx].to_be_bytes();
+            result[idx * 4] = word[0];
+            result[idx * 4 + 1] = word[1];
+            result[idx * 4 + 2] = word[2];
+            result[idx * 4 + 3] = word[3];
+            idx += 1;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sha1;
+
+    #[test]
+    fn should_hash_known_vectors() {
+        assert_eq!(Sha1::new().const_result(), [
+            0xda, 0x39, 0xa3, 0xee, 0x5e, 0x6b, 0x4b, 0x0d, 0x32, 0x55,
+            0xbf, 0xef, 0x95, 0x60, 0x18, 0x90, 0xaf, 0xd8, 0x07, 0x09,
+        ]);
+        assert_eq!(Sha1::new().const_update(b"abc").const_result(), [
+            0xa9, 0x99, 0x3e, 0x36, 0x47, 0x06, 0x81, 0x6a, 0xba, 0x3e,
+            0x25, 0x71, 0x78, 0x50, 0xc2, 0x6c, 0x9c, 0xd0, 0xd8, 0x9d,
+        ]);
+        assert_eq!(Sha1::new().const_update(b"abcdbcdecdefdefgefghfghighijhi").const_update(b"jkijkljklmklmnlmnomnopnopq").const_result(), [
+            0x84, 0x98, 0x3e, 0x44, 0x1c, 0x3b, 0xd2, 0x6e, 0xba, 0xae,
+            0x4a, 0xa1, 0xf9, 0x51, 0x29, 0xe5, 0xe5, 0x46, 0x70, 0xf1,
+        ]);
+    }
+
+    #[cfg(feature = "sha1")]
+    #[test]
+    fn should_match_lhash() {
+        use crate::{Uuid, Version, NAMESPACE_DNS, NAMESPACE_URL, NAMESPACE_OID, NAMESPACE_X500};
+
+        let input = [0xa5u8; 300];
+        for len in 0..input.len() {
+            let expected = lhash::Sha1::new().const_update(&input[..len]).const_result();
+            assert_eq!(Sha1::new().const_update(&input[..len]).const_result(), expected);
+        }
+
+        for namespace in [NAMESPACE_DNS, NAMESPACE_URL, NAMESPACE_OID, NAMESPACE_X500].iter() {
+            for name in ["example.org", "rust-lang.org", ""].iter() {
+                let hash = Sha1::new().const_update(namespace.as_bytes()).const_update(name.as_bytes()).const_result();
+                let uuid = Uuid::from_slice(&hash[..16]).unwrap().set_variant().set_version(Version::Sha1);
+                assert_eq!(uuid, Uuid::v5(*namespace, name.as_bytes()));
+            }
+        }
+    }
+}
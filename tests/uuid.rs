@@ -8,6 +8,29 @@ fn should_convert_from_guid() {
     assert_eq!(uuid.to_str(), "4a35229d-5527-4f30-8647-9dc54e1ee1e8");
 }
 
+#[test]
+fn should_detect_guid_layout() {
+    let uuid = Uuid::from_guid(0x4a35229d, 0x5527, 0x4f30, [0x86, 0x47, 0x9d, 0xc5, 0x4e, 0x1e, 0xe1, 0xe8]);
+    let guid_bytes = [0x9d, 0x22, 0x35, 0x4a, 0x27, 0x55, 0x30, 0x4f, 0x86, 0x47, 0x9d, 0xc5, 0x4e, 0x1e, 0xe1, 0xe8];
+
+    assert_eq!(Uuid::from_bytes_detect_guid(uuid.bytes(), lolid::Version::Random), Some(uuid));
+    assert_eq!(Uuid::from_bytes_detect_guid(guid_bytes, lolid::Version::Random), Some(uuid));
+    assert_eq!(Uuid::from_bytes_detect_guid(guid_bytes, lolid::Version::Mac), None);
+
+    //Both layouts have version 4
+    let ambiguous = [0x9d, 0x22, 0x35, 0x4a, 0x27, 0x55, 0x44, 0x4f, 0x86, 0x47, 0x9d, 0xc5, 0x4e, 0x1e, 0xe1, 0xe8];
+    assert_eq!(Uuid::from_bytes_detect_guid(ambiguous, lolid::Version::Random), None);
+}
+
+#[test]
+fn should_borrow_from_bytes() {
+    let bytes = [254, 255, 100, 1, 0, 255, 255, 253, 40, 20, 150, 125, 130, 140, 200, 99];
+    let uuid = Uuid::from_bytes_ref(&bytes);
+    assert_eq!(uuid.as_bytes(), &bytes);
+    assert_eq!(*uuid, Uuid::from_bytes(bytes));
+    assert_eq!(uuid.to_str(), "feff6401-00ff-fffd-2814-967d828cc863");
+}
+
 #[test]
 fn should_fail_to_create_from_invalid_slice() {
     assert!(Uuid::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]).is_none());
@@ -32,6 +55,62 @@ fn should_convert_uuid_to_str() {
     assert_eq!(uuid.to_string(), "feff6401-00ff-4ffd-a814-967d828cc863");
 }
 
+#[test]
+fn should_precompute_display() {
+    let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    let display = uuid.precompute_display();
+    assert_eq!(display.as_str(), uuid.to_str());
+    assert_eq!(display.to_string(), uuid.to_string());
+
+    let uuid = Uuid::from_slice(&[254, 255, 100, 1, 0, 255, 255, 253, 40, 20, 150, 125, 130, 140, 200, 99]).unwrap();
+    assert_eq!(uuid.precompute_display().as_str(), "feff6401-00ff-fffd-2814-967d828cc863");
+    assert_eq!(Uuid::nil().precompute_display().as_str(), Uuid::nil().to_str());
+
+    const DISPLAY: lolid::DisplayUuid = lolid::NAMESPACE_DNS.precompute_display();
+    assert_eq!(DISPLAY.as_str(), "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+}
+
+#[test]
+fn should_compare_bytes() {
+    const ORDER: core::cmp::Ordering = Uuid::cmp_bytes(&lolid::NAMESPACE_DNS, &lolid::NAMESPACE_URL);
+    assert_eq!(ORDER, core::cmp::Ordering::Less);
+
+    let mut uuids = [
+        lolid::NAMESPACE_X500,
+        Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap(),
+        lolid::NAMESPACE_DNS,
+        Uuid::nil(),
+        lolid::NAMESPACE_OID,
+        lolid::NAMESPACE_URL,
+    ];
+    let mut expected = uuids;
+    expected.sort();
+
+    uuids.sort_by(Uuid::cmp_bytes);
+    assert_eq!(uuids, expected);
+    assert_eq!(uuids[0], Uuid::nil());
+    assert_eq!(Uuid::cmp_bytes(&uuids[2], &uuids[2]), core::cmp::Ordering::Equal);
+    assert_eq!(Uuid::cmp_bytes(&uuids[3], &uuids[2]), core::cmp::Ordering::Greater);
+}
+
+#[test]
+fn should_write_uuid_to_str_buf() {
+    let mut buf = Uuid::nil().to_str();
+
+    let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    uuid.write_to_str_buf(&mut buf);
+    assert_eq!(buf, "60ecb7b6-ba34-5aad-a9ef-9020b1ea210a");
+    assert_eq!(buf, uuid.to_str());
+
+    let uuid = Uuid::from_slice(&[254, 255, 100, 1, 0, 255, 255, 253, 40, 20, 150, 125, 130, 140, 200, 99]).unwrap();
+    uuid.write_to_str_buf(&mut buf);
+    assert_eq!(buf, "feff6401-00ff-fffd-2814-967d828cc863");
+
+    let mut buf = str_buf::StrBuf::<36>::new();
+    uuid.write_to_str_buf(&mut buf);
+    assert_eq!(buf, uuid.to_str());
+}
+
 #[test]
 fn check_v1() {
     const MAC: [u8; 6] = [1, 2, 3, 4, 5, 6];
@@ -50,6 +129,209 @@ fn check_v1() {
     assert!(uuid_next.is_variant());
     assert_ne!(uuid.to_str().as_str(), uuid_next.to_str().as_str());
     assert_eq!(uuid_next.to_str().as_str(), "20616934-4ba2-11e7-8001-010203040506");
+
+    assert_eq!(uuid.clock_seq(), 0);
+    assert_eq!(uuid_next.clock_seq(), 1);
+
+    let uuid = Uuid::v1(lolid::Timestamp::from_unix(time).set_counter(0x3ffe), MAC);
+    assert!(uuid.is_variant());
+    assert_eq!(uuid.clock_seq(), 0x3ffe);
+}
+
+#[test]
+fn check_timestamp_parts() {
+    let time = core::time::Duration::new(1_496_854_535, 812_946_000);
+    let timestamp = lolid::Timestamp::from_unix(time).set_counter(5);
+    assert_eq!(timestamp.ticks(), 0x01E7_4BA2_2061_6934);
+    assert_eq!(timestamp.counter(), 5);
+
+    let parts: (u64, u16) = timestamp.into();
+    assert_eq!(parts, (timestamp.ticks(), timestamp.counter()));
+
+    let restored = lolid::Timestamp::from(parts);
+    assert_eq!(restored.ticks(), timestamp.ticks());
+    assert_eq!(restored.counter(), timestamp.counter());
+}
+
+#[test]
+fn check_builder() {
+    const MAC: [u8; 6] = [1, 2, 3, 4, 5, 6];
+
+    let time = core::time::Duration::new(1_496_854_535, 812_946_000);
+    let expected = Uuid::v1(lolid::Timestamp::from_unix(time).set_counter(1), MAC);
+
+    let uuid = lolid::Builder::new().set_version(lolid::Version::Mac)
+                                    .set_variant()
+                                    .set_time_low(0x2061_6934)
+                                    .set_time_mid(0x4ba2)
+                                    .set_time_high(0x01e7)
+                                    .set_clock_seq(1)
+                                    .set_node(MAC)
+                                    .build();
+    assert_eq!(uuid, expected);
+    assert_eq!(uuid.to_str(), "20616934-4ba2-11e7-8001-010203040506");
+
+    //Fields do not override version and variant bits
+    let uuid = lolid::Builder::from(uuid).set_time_high(0xffff).set_clock_seq(0xffff).build();
+    assert!(uuid.is_version(lolid::Version::Mac));
+    assert!(uuid.is_variant());
+    assert_eq!(uuid.to_str(), "20616934-4ba2-1fff-bfff-010203040506");
+}
+
+#[test]
+fn check_from_fields() {
+    const MAC: [u8; 6] = [1, 2, 3, 4, 5, 6];
+
+    let time = core::time::Duration::new(1_496_854_535, 812_946_000);
+    let expected = Uuid::v1(lolid::Timestamp::from_unix(time).set_counter(1), MAC);
+
+    let uuid = Uuid::from_fields(0x2061_6934, 0x4ba2, 0x11e7, 1, MAC);
+    assert_eq!(uuid, expected);
+
+    let uuid = Uuid::checked_from_fields(lolid::Version::Mac, 0x2061_6934, 0x4ba2, 0x11e7, 1, MAC).unwrap();
+    assert_eq!(uuid, expected);
+
+    let err = Uuid::checked_from_fields(lolid::Version::Random, 0x2061_6934, 0x4ba2, 0x11e7, 1, MAC).unwrap_err();
+    assert_eq!(err, lolid::FieldsError::VersionMismatch(1));
+
+    let err = Uuid::checked_from_fields(lolid::Version::Mac, 0x2061_6934, 0x4ba2, 0x11e7, 0x4001, MAC).unwrap_err();
+    assert_eq!(err, lolid::FieldsError::ClockSeqOverflow(0x4001));
+    assert_eq!(Uuid::from_fields(0x2061_6934, 0x4ba2, 0x11e7, 0x4001, MAC), expected);
+}
+
+#[test]
+fn check_v1_sortable_string() {
+    const MAC: [u8; 6] = [1, 2, 3, 4, 5, 6];
+
+    let older = Uuid::v1(lolid::Timestamp::from_parts(0x0000_0001_FFFF_FFFF, 0), MAC);
+    let newer = Uuid::v1(lolid::Timestamp::from_parts(0x0000_0002_0000_0000, 0), MAC);
+    //time_low comes first, hence raw bytes are not ordered by time
+    assert!(older > newer);
+    assert!(older.sort_key() < newer.sort_key());
+    assert!(older.sortable_string() < newer.sortable_string());
+    assert_eq!(older.sortable_string(), "10000001-ffff-ffff-8000-010203040506");
+
+    let time = core::time::Duration::new(1_496_854_535, 812_946_000);
+    let mut uuids = [
+        Uuid::v1(lolid::Timestamp::from_unix(time), MAC),
+        Uuid::v1(lolid::Timestamp::from_unix(time + core::time::Duration::from_micros(1)), MAC),
+        Uuid::v1(lolid::Timestamp::from_unix(time + core::time::Duration::from_millis(1)), MAC),
+        Uuid::v1(lolid::Timestamp::from_unix(time + core::time::Duration::from_secs(1)), MAC),
+        Uuid::v1(lolid::Timestamp::from_unix(time + core::time::Duration::from_secs(3600)), MAC),
+    ];
+    let expected = uuids;
+    uuids.sort_by_key(|uuid| uuid.sortable_string());
+    assert_eq!(uuids, expected);
+
+    let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    assert_eq!(uuid.sort_key(), uuid.bytes());
+    assert_eq!(uuid.sortable_string(), uuid.to_str());
+}
+
+#[test]
+fn check_v1_node_kind() {
+    let time = lolid::Timestamp::from_unix(core::time::Duration::new(1_496_854_535, 812_946_000));
+
+    let uuid = Uuid::v1(time, [0x00, 0x1b, 0x63, 0x84, 0x45, 0xe6]);
+    assert!(!uuid.node_is_multicast());
+    assert!(!uuid.node_is_local());
+
+    let uuid = Uuid::v1(time, [0x5b, 0x1b, 0x63, 0x84, 0x45, 0xe6]);
+    assert!(uuid.node_is_multicast());
+    assert!(uuid.node_is_local());
+
+    let uuid = Uuid::v1(time, [0x01, 0x1b, 0x63, 0x84, 0x45, 0xe6]);
+    assert!(uuid.node_is_multicast());
+    assert!(!uuid.node_is_local());
+}
+
+#[test]
+fn check_v1_random_node() {
+    struct StubRng(u8);
+
+    impl lolid::Rng for StubRng {
+        fn fill_bytes(&mut self, out: &mut [u8]) {
+            for byt in out.iter_mut() {
+                *byt = self.0;
+                self.0 = self.0.wrapping_add(1);
+            }
+        }
+    }
+
+    let time = lolid::Timestamp::from_unix(core::time::Duration::new(1_496_854_535, 812_946_000));
+
+    let uuid = Uuid::v1_with_node_bytes(time, [0x10, 0x11, 0x12, 0x13, 0x14, 0x15]);
+    assert_eq!(uuid, Uuid::v1(time, [0x10, 0x11, 0x12, 0x13, 0x14, 0x15]));
+    assert!(!uuid.node_is_multicast());
+
+    let uuid = Uuid::v1_random_from(time, &mut StubRng(0x10));
+    assert!(uuid.is_version(lolid::Version::Mac));
+    assert!(uuid.is_variant());
+    assert!(uuid.node_is_multicast());
+    assert_eq!(uuid.node(), [0x11, 0x11, 0x12, 0x13, 0x14, 0x15]);
+    assert_eq!(uuid.to_str(), "20616934-4ba2-11e7-8000-111112131415");
+}
+
+#[test]
+fn check_v1_checked() {
+    let max = lolid::Timestamp::from_parts(0x0FFF_FFFF_FFFF_FFFF, 0);
+    let uuid = Uuid::v1_checked(max, [1, 2, 3, 4, 5, 6]).unwrap();
+    assert_eq!(uuid, Uuid::v1(max, [1, 2, 3, 4, 5, 6]));
+
+    let overflow = lolid::Timestamp::from_parts(0x1000_0000_0000_0000, 0);
+    assert_eq!(Uuid::v1_checked(overflow, [1, 2, 3, 4, 5, 6]).unwrap_err(), lolid::FieldsError::TimestampOverflow(0x1000_0000_0000_0000));
+    assert_eq!(lolid::FieldsError::TimestampOverflow(u64::MAX).to_string(), "Timestamp 18446744073709551615 exceeds 60 bits");
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic]
+fn check_v1_overflow_debug_assert() {
+    Uuid::v1(lolid::Timestamp::from_parts(u64::MAX, 0), [1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn check_timestamp_before() {
+    let time = lolid::Timestamp::from_unix(core::time::Duration::new(1_496_854_535, 812_946_000));
+    let uuid = Uuid::v1(time, [1, 2, 3, 4, 5, 6]);
+
+    assert_eq!(uuid.timestamp_before(lolid::Timestamp::from_parts(time.ticks() + 1, 0)), Some(true));
+    assert_eq!(uuid.timestamp_before(time), Some(false));
+    assert_eq!(uuid.timestamp_before(lolid::Timestamp::from_parts(time.ticks() - 1, 0)), Some(false));
+
+    let uuid = Uuid::v7_precise_from(time, [0; 8]);
+    assert_eq!(uuid.timestamp_before(lolid::Timestamp::from_unix(core::time::Duration::new(1_496_854_536, 0))), Some(true));
+    assert_eq!(uuid.timestamp_before(lolid::Timestamp::from_unix(core::time::Duration::new(1_496_854_535, 0))), Some(false));
+
+    assert_eq!(Uuid::nil().timestamp_before(time), None);
+    assert_eq!(Uuid::v4_from([0xff; 16]).timestamp_before(time), None);
+}
+
+#[cfg(any(feature = "sha1", feature = "sha1-compact"))]
+#[test]
+fn check_v1_hashed_node() {
+    let time = lolid::Timestamp::from_unix(core::time::Duration::new(1_496_854_535, 812_946_000));
+    let later = lolid::Timestamp::from_unix(core::time::Duration::new(1_496_854_536, 0));
+
+    let uuid = Uuid::v1_hashed_node(time, b"localhost");
+    assert!(uuid.is_version(lolid::Version::Mac));
+    assert!(uuid.is_variant());
+    assert!(uuid.node_is_multicast());
+    //sha1("localhost") = 334389048b872a533002b34d73f8c29fd09efc50
+    assert_eq!(uuid.node(), [0x33, 0x43, 0x89, 0x04, 0x8b, 0x87]);
+
+    assert_eq!(Uuid::v1_hashed_node(later, b"localhost").node(), uuid.node());
+    assert_ne!(Uuid::v1_hashed_node(time, b"remotehost").node(), uuid.node());
+}
+
+#[cfg(feature = "osrng")]
+#[test]
+fn check_v1_os_random_node() {
+    let time = lolid::Timestamp::from_unix(core::time::Duration::new(1_496_854_535, 812_946_000));
+
+    let uuid = Uuid::v1_random_from(time, &mut lolid::OsRng);
+    assert!(uuid.node_is_multicast());
+    assert_ne!(uuid, Uuid::v1_random_from(time, &mut lolid::OsRng));
 }
 
 #[cfg(feature = "std")]
@@ -62,6 +344,83 @@ fn check_v1_std() {
     assert_ne!(uuid_after, uuid_before);
 }
 
+#[test]
+fn should_convert_to_u128() {
+    let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    assert_eq!(uuid.as_u128(), 0x60ecb7b6_ba34_5aad_a9ef_9020b1ea210a);
+    assert_eq!(Uuid::from_u128(uuid.as_u128()), uuid);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_atomic_uuid() {
+    const MAC: [u8; 6] = [1, 2, 3, 4, 5, 6];
+    static CURRENT: lolid::AtomicUuid = lolid::AtomicUuid::new(Uuid::nil());
+
+    let uuid = Uuid::v1(lolid::Timestamp::now(), MAC);
+    assert_eq!(CURRENT.load(), Uuid::nil());
+    assert_eq!(CURRENT.compare_exchange(Uuid::nil(), uuid), Ok(Uuid::nil()));
+    assert_eq!(CURRENT.load(), uuid);
+    assert_eq!(CURRENT.compare_exchange(Uuid::nil(), uuid), Err(uuid));
+
+    let next = Uuid::v1(lolid::Timestamp::now().set_counter(1), MAC);
+    assert_eq!(CURRENT.swap(next), uuid);
+    CURRENT.store(Uuid::nil());
+    assert_eq!(CURRENT.load(), Uuid::nil());
+}
+
+#[test]
+fn check_v7_precise() {
+    let time = core::time::Duration::new(1_496_854_535, 812_946_000);
+    let earlier = lolid::Timestamp::from_unix(time);
+    let later = lolid::Timestamp::from_unix(time + core::time::Duration::from_nanos(300));
+
+    let uuid = Uuid::v7_precise_from(earlier, [0xff; 8]);
+    assert_eq!(uuid.version_number(), 7);
+    assert!(uuid.is_variant());
+    assert_eq!(uuid.to_str(), "015c837b-9e84-7f22-bfff-ffffffffffff");
+
+    let next = Uuid::v7_precise_from(later, [0; 8]);
+    assert_eq!(next.to_str(), "015c837b-9e84-7f24-8000-000000000000");
+    assert!(uuid < next);
+}
+
+#[cfg(feature = "osrng")]
+#[test]
+fn check_v7_precise_osrng() {
+    let time = core::time::Duration::new(1_496_854_535, 812_000_000);
+    let mut uuids = [Uuid::nil(); 10];
+    for (idx, uuid) in uuids.iter_mut().enumerate() {
+        let time = time + core::time::Duration::from_micros(idx as u64 * 100);
+        *uuid = Uuid::v7_precise(lolid::Timestamp::from_unix(time));
+        assert_eq!(uuid.version_number(), 7);
+        assert!(uuid.is_variant());
+    }
+
+    assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_v7_system_time() {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let now = SystemTime::now();
+    let millis = now.duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+    let millis = millis.to_be_bytes();
+    let uuid = lolid::Builder::from_bytes([
+        millis[2], millis[3], millis[4], millis[5], millis[6], millis[7],
+        0x0a, 0xbc, 0x9d, 0xef, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab
+    ]).set_version_number(7).set_variant().build();
+
+    let time = uuid.v7_system_time().unwrap();
+    assert!(time <= now);
+    assert!(now.duration_since(time).unwrap() < core::time::Duration::from_millis(1));
+
+    assert!(Uuid::nil().v7_system_time().is_none());
+    assert!(uuid.set_version(lolid::Version::Random).v7_system_time().is_none());
+}
+
 #[cfg(feature = "prng")]
 #[test]
 fn check_random_uuid4_prng() {
@@ -74,6 +433,58 @@ fn check_random_uuid4_prng() {
     assert_ne!(uuid, Uuid::prng().to_string());
 }
 
+#[test]
+fn check_v4_from_seed() {
+    let uuid = Uuid::v4_from_seed(42);
+    assert_eq!(uuid.to_string(), "bdd73226-2feb-4e95-a8ef-e333b266f103");
+    assert!(uuid.is_version(lolid::Version::Random));
+    assert!(uuid.is_variant());
+
+    assert_eq!(Uuid::v4_from_seed(42), uuid);
+    assert_ne!(Uuid::v4_from_seed(43), uuid);
+    assert_ne!(Uuid::v4_from_seed(0), Uuid::v4_from_seed(1));
+}
+
+#[cfg(feature = "prng")]
+#[test]
+fn check_prng_bytes() {
+    let bytes = Uuid::prng_bytes();
+    assert_ne!(bytes, Uuid::prng_bytes());
+    assert_eq!(Uuid::v4_from(bytes).bytes()[..6], bytes[..6]);
+
+    let bytes = Uuid::random_bytes_from(&mut wy::Random::new(42));
+    assert_eq!(bytes, Uuid::random_bytes_from(&mut wy::Random::new(42)));
+    assert_ne!(bytes, Uuid::random_bytes_from(&mut wy::Random::new(43)));
+
+    let mut random = wy::Random::new(42);
+    assert_eq!(Uuid::random_bytes_from(&mut random), bytes);
+    assert_ne!(Uuid::random_bytes_from(&mut random), bytes);
+}
+
+#[cfg(feature = "prng")]
+#[test]
+fn check_seeded_sequence() {
+    let sequence: Vec<Uuid> = Uuid::seeded_sequence(42).take(100).collect();
+    assert_eq!(sequence.len(), 100);
+    assert!(sequence.iter().all(|uuid| uuid.is_version(lolid::Version::Random) && uuid.is_variant()));
+
+    let mut unique = sequence.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), 100);
+
+    assert!(Uuid::seeded_sequence(42).take(100).eq(sequence.iter().copied()));
+    assert_ne!(Uuid::seeded_sequence(43).next(), sequence.first().copied());
+}
+
+#[cfg(feature = "osrng")]
+#[test]
+fn check_random_bytes() {
+    let bytes = Uuid::random_bytes();
+    assert_ne!(bytes, Uuid::random_bytes());
+    assert_ne!(bytes, [0; 16]);
+}
+
 #[cfg(feature = "osrng")]
 #[test]
 fn check_random_uuid4_osrng() {
@@ -86,6 +497,90 @@ fn check_random_uuid4_osrng() {
     assert_ne!(uuid, Uuid::v4().to_string());
 }
 
+#[cfg(feature = "osrng")]
+#[test]
+fn check_random_uuid4_bits_distribution() {
+    const SAMPLES: usize = 100_000;
+    const VERSION_BITS: [usize; 4] = [48, 49, 50, 51];
+    const VARIANT_BITS: [usize; 2] = [64, 65];
+
+    assert_eq!(Uuid::v4_entropy_bits(), 122);
+
+    let mut counts = [0usize; 128];
+    for _ in 0..SAMPLES {
+        let bytes = Uuid::v4().bytes();
+        for (idx, count) in counts.iter_mut().enumerate() {
+            //Bit 0 is the most significant bit of the first byte
+            if bytes[idx / 8] & (0x80 >> (idx % 8)) != 0 {
+                *count += 1;
+            }
+        }
+    }
+
+    assert_eq!([counts[48], counts[49], counts[50], counts[51]], [0, SAMPLES, 0, 0]);
+    assert_eq!([counts[64], counts[65]], [SAMPLES, 0]);
+
+    let expected = SAMPLES as f64 / 2.0;
+    let mut chi_square = 0.0;
+    let mut random_bits = 0;
+    for (idx, count) in counts.iter().enumerate() {
+        if VERSION_BITS.contains(&idx) || VARIANT_BITS.contains(&idx) {
+            continue;
+        }
+
+        random_bits += 1;
+        let frequency = *count as f64 / SAMPLES as f64;
+        assert!((frequency - 0.5).abs() < 0.01, "Bit {} has frequency {}", idx, frequency);
+        chi_square += (*count as f64 - expected).powi(2) / expected;
+    }
+
+    assert_eq!(random_bits, Uuid::v4_entropy_bits());
+    //Expected value is 122 with standard deviation ~15.6
+    assert!(chi_square < 200.0, "Chi-square {} is too high", chi_square);
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn check_collision_probability() {
+    assert_eq!(Uuid::collision_probability(0), 0.0);
+    assert!(Uuid::collision_probability(1_000_000) > 0.0);
+
+    let probability = Uuid::collision_probability(326_000_000_000_000_000);
+    assert!((probability - 0.01).abs() < 0.0005, "Probability {}", probability);
+
+    let probability = Uuid::collision_probability(2_710_000_000_000_000_000);
+    assert!((probability - 0.5).abs() < 0.005, "Probability {}", probability);
+
+    assert!(Uuid::collision_probability(u64::MAX) > 0.999_999);
+}
+
+#[cfg(any(feature = "sha1", feature = "sha1-compact"))]
+#[test]
+fn check_uuid5_checked() {
+    assert_eq!(Uuid::v5_checked(Uuid::nil(), b"x").unwrap_err(), lolid::GenerateError::NilNamespace);
+    assert_eq!(Uuid::v5_checked(lolid::NAMESPACE_DNS, b"x").unwrap(), Uuid::v5(lolid::NAMESPACE_DNS, b"x"));
+    assert_eq!(lolid::GenerateError::NilNamespace.to_string(), "Namespace is nil");
+}
+
+#[cfg(feature = "md5")]
+#[test]
+fn check_uuid3_with_digest() {
+    let (uuid, digest) = Uuid::v3_with_digest(lolid::NAMESPACE_DNS, "example.org".as_bytes());
+    assert_eq!(uuid, Uuid::v3(lolid::NAMESPACE_DNS, "example.org".as_bytes()));
+    assert_eq!(uuid.bytes()[..6], digest[..6]);
+    assert_eq!(Uuid::v4_from(digest).set_version(lolid::Version::Md5), uuid);
+}
+
+#[cfg(any(feature = "sha1", feature = "sha1-compact"))]
+#[test]
+fn check_uuid5_with_digest() {
+    let (uuid, digest) = Uuid::v5_with_digest(lolid::NAMESPACE_DNS, "example.org".as_bytes());
+    assert_eq!(uuid, Uuid::v5(lolid::NAMESPACE_DNS, "example.org".as_bytes()));
+    assert_eq!(uuid.bytes()[..6], digest[..6]);
+    assert_eq!(uuid.bytes()[9..], digest[9..16]);
+    assert_eq!(Uuid::from_slice(&digest[..16]).unwrap().set_variant().set_version(lolid::Version::Sha1), uuid);
+}
+
 #[cfg(feature = "md5")]
 #[test]
 fn check_uuid3() {
@@ -130,7 +625,7 @@ fn check_uuid3() {
     assert_eq!(uuid, "bcee7a9c-52f1-30c6-a3cc-8c72ba634990");
 }
 
-#[cfg(feature = "sha1")]
+#[cfg(any(feature = "sha1", feature = "sha1-compact"))]
 #[test]
 fn check_uuid5() {
     let uuid = Uuid::v5(lolid::NAMESPACE_DNS, "example.org".as_bytes());
@@ -174,6 +669,39 @@ fn check_uuid5() {
     assert_eq!(uuid, "26c9c3e9-49b7-56da-8b9f-a0fb916a71a3");
 }
 
+#[cfg(feature = "md5")]
+#[test]
+fn check_const_uuid3() {
+    const UUID: Uuid = lolid::const_v3!(lolid::NAMESPACE_DNS, "rust-lang.org");
+    let name = String::from("rust-lang.org");
+    assert_eq!(UUID, Uuid::v3(lolid::NAMESPACE_DNS, name.as_bytes()));
+    assert_eq!(UUID.to_str(), "c6db027c-615c-3b4d-959e-1a917747ca5a");
+}
+
+#[cfg(any(feature = "sha1", feature = "sha1-compact"))]
+#[test]
+fn check_const_uuid5() {
+    const UUID: Uuid = lolid::const_v5!(lolid::NAMESPACE_DNS, "example.org");
+    let name = String::from("example.org");
+    assert_eq!(UUID, Uuid::v5(lolid::NAMESPACE_DNS, name.as_bytes()));
+    assert_eq!(UUID.to_str(), "aad03681-8b63-5304-89e0-8ca8f49461b5");
+}
+
+#[cfg(any(feature = "sha1", feature = "sha1-compact"))]
+#[test]
+fn check_uuid5_from_iter() {
+    let uuid = Uuid::v5_from_iter(lolid::NAMESPACE_URL, ["rust", "-lang", ".org"].iter());
+    assert_eq!(uuid, Uuid::v5(lolid::NAMESPACE_URL, b"rust-lang.org"));
+    assert_eq!(uuid.to_str(), "c48d927f-4122-5413-968c-598b1780e749");
+
+    let blob = [0x5au8; 1000];
+    let uuid = Uuid::v5_from_iter(lolid::NAMESPACE_OID, blob.chunks(77));
+    assert_eq!(uuid, Uuid::v5(lolid::NAMESPACE_OID, &blob));
+
+    let uuid = Uuid::v5_from_iter(lolid::NAMESPACE_DNS, core::iter::empty::<&[u8]>());
+    assert_eq!(uuid, Uuid::v5(lolid::NAMESPACE_DNS, b""));
+}
+
 #[test]
 fn check_parse_str() {
     let parsed = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
@@ -209,7 +737,24 @@ fn check_parse_str() {
     assert_eq!(err, lolid::ParseError::InvalidLength(33));
 
     let err = Uuid::parse_str("60ecb7b-ba34-5aad-a9ef-9020b1ea210a").unwrap_err();
-    assert_eq!(err, lolid::ParseError::InvalidLength(35));
+    assert_eq!(err, lolid::ParseError::InvalidGroupLen(1, 0, 7));
+
+    let err = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef0-9020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidGroupLen(4, 19, 5));
+
+    let err = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidGroupLen(5, 24, 11));
+
+    let err = Uuid::parse_str("urn:uuid:60ecb7b6-ba3-5aad-a9ef-9020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidGroupLen(2, 18, 3));
+
+    //Length error wins when input isn't made of 5 groups
+    let err = Uuid::parse_str("60ecb7b-ba34-5aad-a9ef9020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(34));
+
+    let err = Uuid::parse_str("60ecb7b-ba34-5aad-a9ef-9020b1ea210a--").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(37));
+    assert_eq!(lolid::ParseError::InvalidGroupLen(1, 0, 7).to_string(), "Group 1 at position 0 has unexpected length 7");
 
     let err = Uuid::parse_str("60ecb7b6gba34g5aadga9efg9020b1ea210a").unwrap_err();
     assert_eq!(err, lolid::ParseError::InvalidGroup(1));
@@ -225,4 +770,502 @@ fn check_parse_str() {
 
     let err = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a-").unwrap_err();
     assert_eq!(err, lolid::ParseError::InvalidLength(37));
+
+    let err = Uuid::parse_str("60ecb7b6\u{2013}ba34-5aad-a9ef-9020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(0xe2, 8));
+
+    //Same length as valid UUID
+    let err = Uuid::parse_str("60ecb7b6\u{2013}ba34-5aad-a9ef-9020b1ea21").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(0xe2, 8));
+
+    let err = Uuid::parse_str("60ecb7b6-ba34-5aad\u{2013}a9ef-9020b1ea21").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(0xe2, 18));
+
+    let err = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210\u{e9}").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(0xc3, 35));
+}
+
+#[test]
+fn check_try_parse_never_panics() {
+    const INPUTS: [&str; 6] = [
+        "60ecb7b6-ba34-5aad-a9ef-9020b1ea210a",
+        "urn%3Auuid%3A60ecb7b6-ba34-5aad-a9ef-9020b1ea210a",
+        "0x60ecb7b6ba345aada9ef9020b1ea210a",
+        "60ecb7b6\u{2013}ba34-5aad-a9ef-9020b1ea210\u{e9}",
+        "\u{1f600}\u{1f600}\u{1f600}\u{1f600}\u{1f600}\u{1f600}\u{1f600}\u{1f600}\u{1f600}",
+        "--------------------------------------------------",
+    ];
+
+    for input in INPUTS.iter() {
+        for (end, _) in input.char_indices() {
+            let input = &input[..end];
+            let _ = Uuid::try_parse(input);
+            let _ = Uuid::parse_prefix(input);
+            let _ = input.parse::<lolid::Simple>();
+            let _ = input.parse::<lolid::Hyphenated>();
+        }
+    }
+
+    assert_eq!(Uuid::try_parse("").unwrap_err(), lolid::ParseError::InvalidLength(0));
+    assert_eq!(Uuid::try_parse("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap(), Uuid::parse_str("60ecb7b6ba345aada9ef9020b1ea210a").unwrap());
+}
+
+#[test]
+fn check_parse_prefix() {
+    let expected = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+
+    let (uuid, consumed) = Uuid::parse_prefix("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a rest of line").unwrap();
+    assert_eq!(uuid, expected);
+    assert_eq!(consumed, 36);
+
+    let (uuid, consumed) = Uuid::parse_prefix("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    assert_eq!(uuid, expected);
+    assert_eq!(consumed, 36);
+
+    let (uuid, consumed) = Uuid::parse_prefix("60ecb7b6ba345aada9ef9020b1ea210a,60ecb7b6ba345aada9ef9020b1ea210a").unwrap();
+    assert_eq!(uuid, expected);
+    assert_eq!(consumed, 32);
+
+    let err = Uuid::parse_prefix("60ecb7b6-ba34-5aad-a9ef-9020b1ea210").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(35));
+
+    let err = Uuid::parse_prefix("60ecb7b6ba345aada9ef9020b1ea210").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(31));
+
+    let err = Uuid::parse_prefix("60ecb7b6-ba34-5aad-a9ef-9020b1ea210, rest of line").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b',', 35));
+
+    let err = Uuid::parse_prefix("60ecb7b6 ba34-5aad-a9ef-9020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b' ', 8));
+}
+
+#[test]
+fn check_parse_iter() {
+    let input = "6ba7b810-9dad-11d1-80b4-00c04fd430c8,60ecb7b6ba345aada9ef9020b1ea210a,60ecb7b6-ba34-5aad-a9ef-9020b1ea210x,,6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+    let result: Vec<_> = Uuid::parse_iter(input, ',').collect();
+
+    assert_eq!(result.len(), 5);
+    assert_eq!(result[0], Uuid::parse_str("6ba7b810-9dad-11d1-80b4-00c04fd430c8"));
+    assert_eq!(result[1], Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a"));
+    assert_eq!(result[2], Err(lolid::ParseError::InvalidByte(b'x', 35)));
+    assert_eq!(result[3], Err(lolid::ParseError::InvalidLength(0)));
+    assert_eq!(result[4], result[0]);
+
+    let mut iter = Uuid::parse_iter("6ba7b810-9dad-11d1-80b4-00c04fd430c8\n", '\n');
+    assert!(iter.next().unwrap().is_ok());
+    assert_eq!(iter.next(), Some(Err(lolid::ParseError::InvalidLength(0))));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn check_hyphenate_simple() {
+    let mut out = [0u8; 36];
+
+    Uuid::hyphenate_simple("6ba7b8109dad11d180b400c04fd430c8", &mut out).unwrap();
+    assert_eq!(&out, b"6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    Uuid::hyphenate_simple("6BA7B8109DAD11D180B400C04FD430C8", &mut out).unwrap();
+    assert_eq!(&out, b"6BA7B810-9DAD-11D1-80B4-00C04FD430C8");
+
+    assert_eq!(Uuid::hyphenate_simple("6ba7b8109dad11d180b400c04fd430c", &mut out).unwrap_err(), lolid::ParseError::InvalidLength(31));
+    assert_eq!(Uuid::hyphenate_simple("6ba7b8109dad11d180b400c04fd430cg", &mut out).unwrap_err(), lolid::ParseError::InvalidByte(b'g', 31));
+    assert_eq!(Uuid::hyphenate_simple("6ba7b810-9dad-11d1-80b4-00c04fd430c8", &mut out).unwrap_err(), lolid::ParseError::InvalidLength(36));
+}
+
+#[test]
+fn check_parse_cstr() {
+    let expected = Uuid::parse_str("6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap();
+
+    assert_eq!(Uuid::parse_cstr("6ba7b810-9dad-11d1-80b4-00c04fd430c8\0").unwrap(), expected);
+    assert_eq!(Uuid::parse_cstr("6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap(), expected);
+    assert_eq!(Uuid::parse_cstr("6ba7b8109dad11d180b400c04fd430c8\0").unwrap(), expected);
+
+    assert_eq!(Uuid::parse_str("6ba7b810-9dad-11d1-80b4-00c04fd430c8\0").unwrap_err(), lolid::ParseError::InvalidGroupLen(5, 24, 13));
+    assert_eq!(Uuid::parse_cstr("6ba7b810-9dad-11d1-80b4-00c04fd430c8\0\0").unwrap_err(), lolid::ParseError::InvalidGroupLen(5, 24, 13));
+    assert_eq!(Uuid::parse_cstr("6ba7b810-9dad-11d1-80b4-00c04fd4\x000c8\0").unwrap_err(), lolid::ParseError::InvalidByte(0, 32));
+    assert_eq!(Uuid::parse_cstr("\0").unwrap_err(), lolid::ParseError::InvalidLength(0));
+}
+
+#[test]
+fn check_parse_byte_list() {
+    let expected = Uuid::parse_str("6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap();
+
+    assert_eq!(Uuid::parse_byte_list("6b a7 b8 10 9d ad 11 d1 80 b4 00 c0 4f d4 30 c8").unwrap(), expected);
+    assert_eq!(Uuid::parse_byte_list("6b,a7,b8,10,9d,ad,11,d1,80,b4,00,c0,4f,d4,30,c8").unwrap(), expected);
+    assert_eq!(Uuid::parse_byte_list(" 6B, A7, B8, 10, 9D, AD, 11, D1, 80, B4, 00, C0, 4F, D4, 30, C8 ").unwrap(), expected);
+
+    let err = Uuid::parse_byte_list("6b a7 b8 10 9d ad 11 d1 80 b4 00 c0 4f d4 30").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(15));
+    let err = Uuid::parse_byte_list("6b a7 b8 10 9d ad 11 d1 80 b4 00 c0 4f d4 30 c8 00").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(17));
+    let err = Uuid::parse_byte_list("6b a7 b8 10 9d ad 11 d1 80 b4 00 c0 4f d4 30c8").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidGroupLen(15, 42, 4));
+    let err = Uuid::parse_byte_list("6b a7 b8 10 9d ad 11 d1 80 b4 00 c0 4f d4 30 cx").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b'x', 46));
+    assert_eq!(Uuid::parse_byte_list("").unwrap_err(), lolid::ParseError::InvalidLength(0));
+}
+
+#[test]
+fn check_parse_str_urn() {
+    let expected = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+
+    let parsed = Uuid::parse_str("urn:uuid:60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    assert_eq!(parsed, expected);
+
+    let parsed = Uuid::parse_str("URN:UUID:60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    assert_eq!(parsed, expected);
+
+    let parsed = Uuid::parse_str("urn%3Auuid%3A60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    assert_eq!(parsed, expected);
+
+    let parsed = Uuid::parse_str("urn%3auuid%3a60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    assert_eq!(parsed, expected);
+
+    let err = Uuid::parse_str("urn%3Auuid:60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidGroupLen(1, 0, 19));
+
+    let err = Uuid::parse_str("urn%3Auuid%3A60ecb7b6ba345aada9ef9020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(45));
+
+    let err = Uuid::parse_str("urn:uuid:60ecb7b6-ba34-5aad-a9ef-9020b1ea210,").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b',', 44));
+
+    let err = Uuid::parse_str("urn:uuid:60ecb7b6-ba34-5aad-a9efg9020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidGroup(4));
+}
+
+#[test]
+fn check_parse_str_hex_prefix() {
+    let expected = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+
+    let parsed = Uuid::parse_str("0x60ecb7b6ba345aada9ef9020b1ea210a").unwrap();
+    assert_eq!(parsed, expected);
+
+    let parsed = Uuid::parse_str("0X60ECB7B6BA345AADA9EF9020B1EA210A").unwrap();
+    assert_eq!(parsed, expected);
+
+    let err = Uuid::parse_str("0x60ecb7b6ba345aada9ef9020b1ea210").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(33));
+
+    let err = Uuid::parse_str("0x60ecb7b6ba345aada9ef9020b1ea210a0").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(35));
+
+    let err = Uuid::parse_str("0x60ecb7b6ba345aada9ef9020b1ea210,").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b',', 33));
+
+    let err = Uuid::parse_str("0y60ecb7b6ba345aada9ef9020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(34));
+}
+
+#[test]
+fn should_set_version_number() {
+    let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    assert_eq!(uuid.version_number(), 5);
+
+    let uuid = uuid.with_version_number(9);
+    assert_eq!(uuid.version_number(), 9);
+    assert_eq!(uuid.to_str(), "60ecb7b6-ba34-9aad-a9ef-9020b1ea210a");
+
+    let uuid = uuid.with_version_number(0xf4);
+    assert_eq!(uuid.version_number(), 4);
+    assert!(uuid.is_version(lolid::Version::Random));
+}
+
+#[test]
+fn should_map_bytes_and_re_stamp() {
+    let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    let mapped = uuid.map_bytes(|mut bytes| {
+        for byt in bytes.iter_mut() {
+            *byt ^= 0xff;
+        }
+        bytes
+    });
+    assert_eq!(mapped.to_str(), "9f134849-45cb-a552-5610-6fdf4e15def5");
+    assert!(!mapped.is_variant());
+    assert!(!mapped.is_version(lolid::Version::Sha1));
+
+    let mapped = mapped.re_stamp(lolid::Version::Sha1);
+    assert!(mapped.is_variant());
+    assert!(mapped.is_version(lolid::Version::Sha1));
+    assert_eq!(mapped.to_str(), "9f134849-45cb-5552-9610-6fdf4e15def5");
+}
+
+#[cfg(feature = "osrng")]
+#[test]
+fn check_generate_v4() {
+    let uuid = Uuid::generate(lolid::Version::Random, lolid::GenerateOpts::new()).unwrap();
+    assert!(uuid.is_version(lolid::Version::Random));
+    assert!(uuid.is_variant());
+}
+
+#[cfg(any(feature = "sha1", feature = "sha1-compact"))]
+#[test]
+fn check_generate_v5() {
+    let opts = lolid::GenerateOpts::new().name(lolid::NAMESPACE_DNS, b"example.org");
+    let uuid = Uuid::generate(lolid::Version::Sha1, opts).unwrap();
+    assert_eq!(uuid, Uuid::v5(lolid::NAMESPACE_DNS, b"example.org"));
+
+    let opts = lolid::GenerateOpts {
+        namespace: Some(lolid::NAMESPACE_DNS),
+        ..Default::default()
+    };
+    let err = Uuid::generate(lolid::Version::Sha1, opts).unwrap_err();
+    assert_eq!(err, lolid::GenerateError::MissingName);
+}
+
+#[test]
+fn check_generate() {
+    const MAC: [u8; 6] = [1, 2, 3, 4, 5, 6];
+    let timestamp = lolid::Timestamp::from_unix(core::time::Duration::new(1_496_854_535, 812_946_000));
+
+    let uuid = Uuid::generate(lolid::Version::Mac, lolid::GenerateOpts::new().timestamp(timestamp).node(MAC)).unwrap();
+    assert_eq!(uuid, Uuid::v1(timestamp, MAC));
+
+    let err = Uuid::generate(lolid::Version::Mac, lolid::GenerateOpts::new().node(MAC)).unwrap_err();
+    assert_eq!(err, lolid::GenerateError::MissingTimestamp);
+
+    let err = Uuid::generate(lolid::Version::Dce, lolid::GenerateOpts::new()).unwrap_err();
+    assert_eq!(err, lolid::GenerateError::Unsupported(lolid::Version::Dce));
+
+    assert_eq!(Uuid::generate(lolid::Version::Nil, lolid::GenerateOpts::new()).unwrap(), Uuid::nil());
+}
+
+#[test]
+fn check_simple_and_hyphenated_forms() {
+    let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+
+    assert_eq!(uuid.simple().to_string(), "60ecb7b6ba345aada9ef9020b1ea210a");
+    assert_eq!(uuid.hyphenated().to_string(), "60ecb7b6-ba34-5aad-a9ef-9020b1ea210a");
+
+    let simple: lolid::Simple = "60ecb7b6ba345aada9ef9020b1ea210a".parse().unwrap();
+    assert_eq!(simple.0, uuid);
+    let hyphenated: lolid::Hyphenated = "60ecb7b6-ba34-5aad-a9ef-9020b1ea210a".parse().unwrap();
+    assert_eq!(hyphenated.0, uuid);
+
+    let err = "60ecb7b6-ba34-5aad-a9ef-9020b1ea210a".parse::<lolid::Simple>().unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(36));
+    let err = "0x60ecb7b6ba345aada9ef9020b1ea210a".parse::<lolid::Simple>().unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(34));
+    let err = "60ecb7b6ba345aada9ef9020b1ea210a".parse::<lolid::Hyphenated>().unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(32));
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn check_ct_eq() {
+    let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    let same = Uuid::parse_str("60ecb7b6ba345aada9ef9020b1ea210a").unwrap();
+    let other = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210b").unwrap();
+
+    assert!(bool::from(uuid.ct_eq(&same)));
+    assert!(!bool::from(uuid.ct_eq(&other)));
+    assert!(!bool::from(uuid.ct_eq(&Uuid::nil())));
+}
+
+#[test]
+fn check_version_wrappers() {
+    let time = lolid::Timestamp::from_unix(core::time::Duration::new(1_496_854_535, 812_946_000));
+    let v4 = Uuid::v4_from([0xa5; 16]);
+    let v7 = Uuid::v7_precise_from(time, [0; 8]);
+
+    assert!(lolid::V7::try_new(v4).is_none());
+    assert!(lolid::V7::try_new(Uuid::nil()).is_none());
+    let wrapped = lolid::V7::try_new(v7).unwrap();
+    assert_eq!(wrapped.version_number(), 7);
+    assert_eq!(*wrapped, v7);
+    assert_eq!(Uuid::from(wrapped), v7);
+    assert_eq!(wrapped.to_string(), v7.to_string());
+
+    assert!(lolid::V4::try_new(v7).is_none());
+    assert_eq!(lolid::V4::try_new(v4).unwrap().into_inner(), v4);
+}
+
+#[test]
+fn check_format_at() {
+    let uuid = Uuid::parse_str("6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap();
+    let mut buf = [b'.'; 64];
+
+    assert_eq!(uuid.format_at(&mut buf, 14, lolid::Format::Hyphenated), Ok(36));
+    assert_eq!(&buf[..14], b"..............");
+    assert_eq!(&buf[14..50], b"6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    assert_eq!(&buf[50..], b"..............");
+
+    assert_eq!(uuid.format_at(&mut buf, 0, lolid::Format::Simple), Ok(32));
+    assert_eq!(&buf[..32], b"6ba7b8109dad11d180b400c04fd430c8");
+    assert_eq!(uuid.format_at(&mut buf, 19, lolid::Format::Urn), Ok(45));
+    assert_eq!(&buf[19..], b"urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+
+    assert_eq!(uuid.format_at(&mut buf, 28, lolid::Format::Hyphenated), Ok(36));
+    assert_eq!(uuid.format_at(&mut buf, 29, lolid::Format::Urn), Err(()));
+    assert_eq!(uuid.format_at(&mut buf, usize::MAX, lolid::Format::Simple), Err(()));
+    assert_eq!(uuid.format_at(&mut [], 0, lolid::Format::Simple), Err(()));
+}
+
+#[test]
+fn check_with_hyphenated() {
+    let first = Uuid::parse_str("6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap();
+    let second = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+
+    assert_eq!(first.with_hyphenated(|text| text.to_owned()), "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    assert_eq!(second.with_hyphenated(|text| text.to_owned()), "60ecb7b6-ba34-5aad-a9ef-9020b1ea210a");
+    assert_eq!(first.with_hyphenated(|text| text.len()), 36);
+
+    let nested = first.with_hyphenated(|outer| second.with_hyphenated(|inner| format!("{} {}", outer, inner)));
+    assert_eq!(nested, "6ba7b810-9dad-11d1-80b4-00c04fd430c8 60ecb7b6-ba34-5aad-a9ef-9020b1ea210a");
+}
+
+#[test]
+fn check_reverse_bytes() {
+    let uuid = Uuid::parse_str("6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap();
+    let reversed = uuid.reverse_bytes();
+
+    let mut expected = uuid.bytes();
+    expected.reverse();
+    assert_eq!(reversed.bytes(), expected);
+    assert_eq!(reversed.to_string(), "c830d44f-c000-b480-d111-ad9d10b8a76b");
+    assert_eq!(reversed.reverse_bytes(), uuid);
+}
+
+#[cfg(all(feature = "std", feature = "osrng"))]
+#[test]
+fn check_v7_fill() {
+    let mut out = [Uuid::nil(); 1000];
+    Uuid::v7_fill(&mut out);
+
+    assert!(out.iter().all(|uuid| uuid.version_number() == 7 && uuid.is_variant()));
+    assert!(out.windows(2).all(|pair| pair[0] < pair[1]));
+
+    //Overflow of counter moves to the next millisecond
+    let mut out = vec![Uuid::nil(); 5000];
+    Uuid::v7_fill(&mut out);
+    assert!(out.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(out[4095].v7_system_time().unwrap() + core::time::Duration::from_millis(1), out[4096].v7_system_time().unwrap());
+}
+
+#[test]
+fn check_i64_pair() {
+    let uuid = Uuid::parse_str("c830d44f-c000-b480-6ba7-b8109dad11d1").unwrap();
+    let (high, low) = uuid.as_i64_pair();
+    assert!(high < 0);
+    assert_eq!(high as u64, 0xc830d44fc000b480);
+    assert_eq!(low, 0x6ba7b8109dad11d1);
+    assert_eq!(Uuid::from_i64_pair(high, low), uuid);
+
+    assert_eq!(Uuid::from_i64_pair(-1, -1), Uuid::from_bytes([0xff; 16]));
+    assert_eq!(Uuid::from_i64_pair(i64::MIN, 1).as_i64_pair(), (i64::MIN, 1));
+    assert_eq!(Uuid::nil().as_i64_pair(), (0, 0));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn check_rand_distribution() {
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    let uuid: Uuid = rng.gen();
+    assert!(uuid.is_version(lolid::Version::Random));
+    assert!(uuid.is_variant());
+    assert_ne!(uuid, rng.gen::<Uuid>());
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    assert_eq!(rng.gen::<Uuid>(), uuid);
+}
+
+#[test]
+fn check_to_str_case() {
+    let uuid = Uuid::parse_str("6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap();
+    assert_eq!(uuid.to_str_upper().as_str(), "6BA7B810-9DAD-11D1-80B4-00C04FD430C8");
+    assert_eq!(uuid.to_str_case::<false>().as_str(), uuid.to_str().as_str());
+    assert_eq!(uuid.to_str_case::<true>().as_str(), uuid.to_str_upper().as_str());
+
+    assert_eq!(Uuid::nil().to_str_upper().as_str(), Uuid::nil().to_str().as_str());
+    let max = Uuid::from_bytes([0xff; 16]);
+    assert_eq!(max.to_str().as_str(), "ffffffff-ffff-ffff-ffff-ffffffffffff");
+    assert_eq!(max.to_str_upper().as_str(), "FFFFFFFF-FFFF-FFFF-FFFF-FFFFFFFFFFFF");
+    assert_eq!(Uuid::parse_str(max.to_str_upper().as_str()).unwrap(), max);
+}
+
+#[test]
+fn check_binary_format() {
+    let uuid = Uuid::v4_from([0; 16]);
+    let expected = "00000000_00000000_00000000_00000000_00000000_00000000_01000000_00000000_10000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000";
+    assert_eq!(format!("{:b}", uuid), expected);
+    assert_eq!(format!("{:#b}", uuid), format!("0b{}", expected));
+
+    let uuid = Uuid::from_bytes([0xff; 16]).set_variant().set_version(lolid::Version::Sha1);
+    let binary = format!("{:b}", uuid);
+    assert_eq!(binary.len(), 128 + 15);
+    assert_eq!(&binary[54..62], "01011111");
+    assert_eq!(&binary[72..80], "10111111");
+}
+
+#[test]
+fn check_from_bytes_rfc4122() {
+    let bytes = [0x60, 0xec, 0xb7, 0xb6, 0xba, 0x34, 0x4a, 0xad, 0xa9, 0xef, 0x90, 0x20, 0xb1, 0xea, 0x21, 0x0a];
+    let uuid = Uuid::from_bytes_rfc4122(bytes).unwrap();
+    assert_eq!(uuid, Uuid::from_bytes(bytes));
+    assert_eq!(uuid.variant(), lolid::Variant::Rfc4122);
+
+    let mut microsoft = bytes;
+    microsoft[8] = 0xc9;
+    assert_eq!(Uuid::from_bytes(microsoft).variant(), lolid::Variant::Microsoft);
+    assert_eq!(Uuid::from_bytes_rfc4122(microsoft).unwrap_err(), lolid::ParseError::InvalidVariant(lolid::Variant::Microsoft));
+
+    assert_eq!(Uuid::from_bytes_rfc4122([0; 16]).unwrap_err(), lolid::ParseError::InvalidVariant(lolid::Variant::Ncs));
+    assert_eq!(Uuid::from_bytes([0xff; 16]).variant(), lolid::Variant::Future);
+
+    let mut invalid_version = bytes;
+    invalid_version[6] = 0xfa;
+    assert_eq!(Uuid::from_bytes_rfc4122(invalid_version).unwrap_err(), lolid::ParseError::InvalidVersion(15));
+    assert_eq!(lolid::ParseError::InvalidVersion(15).to_string(), "Unexpected version 15");
+    assert_eq!(lolid::ParseError::InvalidVariant(lolid::Variant::Microsoft).to_string(), "Unexpected variant Microsoft");
+}
+
+#[test]
+fn check_v1_state() {
+    let mut state = lolid::V1State::new();
+
+    let ticks = [100, 100, 99, 100, 101, 50, 101, 102];
+    let timestamps: Vec<_> = ticks.iter().map(|ticks| state.next_timestamp(*ticks).unwrap().into_parts()).collect();
+    assert_eq!(timestamps, [(100, 0), (100, 1), (100, 2), (100, 3), (101, 0), (101, 1), (101, 2), (102, 0)]);
+
+    let mut state = lolid::V1State::default();
+    let mut uuids: Vec<_> = (0..0x4000 + 10).map(|_| Uuid::v1(state.next_timestamp(1000).unwrap(), [1, 2, 3, 4, 5, 6])).collect();
+    assert_eq!(state.next_timestamp(1000).unwrap().into_parts(), (1001, 10));
+
+    let len = uuids.len();
+    uuids.sort();
+    uuids.dedup();
+    assert_eq!(uuids.len(), len);
+}
+
+#[test]
+fn check_v1_state_overflow() {
+    const MAX: u64 = 0x0FFF_FFFF_FFFF_FFFF;
+    let mut state = lolid::V1State::new();
+
+    assert_eq!(state.next_timestamp(u64::MAX).unwrap_err(), lolid::FieldsError::TimestampOverflow(u64::MAX));
+    assert_eq!(state.next_timestamp(MAX + 1).unwrap_err(), lolid::FieldsError::TimestampOverflow(MAX + 1));
+
+    assert_eq!(state.next_timestamp(MAX).unwrap().into_parts(), (MAX, 0));
+    for counter in 1..=0x3FFF {
+        assert_eq!(state.next_timestamp(MAX).unwrap().into_parts(), (MAX, counter));
+    }
+    assert_eq!(state.next_timestamp(MAX).unwrap_err(), lolid::FieldsError::TimestampOverflow(MAX + 1));
+    assert_eq!(state.next_timestamp(0).unwrap_err(), lolid::FieldsError::TimestampOverflow(MAX + 1));
+}
+
+#[test]
+fn check_nullable_nil_equals_none() {
+    use std::hash::{BuildHasher, BuildHasherDefault};
+    use std::collections::hash_map::DefaultHasher;
+
+    let hasher = BuildHasherDefault::<DefaultHasher>::default();
+    let uuid = Uuid::parse_str("6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap();
+
+    assert_eq!(lolid::Nullable::from(Uuid::nil()), lolid::Nullable(None));
+    assert_eq!(lolid::Nullable(Some(Uuid::nil())), lolid::Nullable(None));
+    assert_eq!(hasher.hash_one(lolid::Nullable(Some(Uuid::nil()))), hasher.hash_one(lolid::Nullable(None)));
+    assert_ne!(lolid::Nullable(Some(uuid)), lolid::Nullable(None));
+    assert!(lolid::Nullable(None) < lolid::Nullable(Some(uuid)));
+    assert_eq!(lolid::Nullable(Some(Uuid::nil())).cmp(&lolid::Nullable(None)), core::cmp::Ordering::Equal);
 }